@@ -0,0 +1,275 @@
+use advent_of_code_2025_day1::{
+    count_streamed, count_target_occurrences, dump_frames, parse_wheel_rotations, Animation, CountMode, Day1,
+    Dial, Lock, Overflow, Ring, Rotation, Script, StreamError, Sweep, Trace, TraceEvent, DIAL_POSITIONS,
+    START_POSITION,
+};
+use aoc_common::solution::load_input_from;
+use aoc_common::{report, InputLocator, ParseError, Solution};
+use clap::{Parser, ValueEnum};
+use std::error::Error;
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Counts how often the safe's dial reaches 0.
+#[derive(Parser)]
+struct Args {
+    /// Read the rotations from this file, or from stdin if "-", instead of the default input.
+    #[arg(long)]
+    input: Option<String>,
+    /// Only count in this mode: "ends" for rotations ending on 0, "passes" for every click onto 0.
+    #[arg(long)]
+    mode: Option<CountMode>,
+    /// Count these positions instead of 0, e.g. "--target 0,25,50".
+    #[arg(
+        long = "target",
+        value_name = "POSITION",
+        value_delimiter = ',',
+        value_parser = clap::value_parser!(i64).range(0..DIAL_POSITIONS),
+    )]
+    targets: Vec<i64>,
+    /// Print every rotation and every time the dial reaches 0, counting in --mode (default "passes").
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "table",
+        conflicts_with = "targets"
+    )]
+    trace: Option<TraceFormat>,
+    /// Include every single click in the trace.
+    #[arg(long, requires = "trace")]
+    clicks: bool,
+    /// Count from every starting position and print the best and worst.
+    #[arg(long, conflicts_with_all = ["targets", "trace"])]
+    sweep: bool,
+    /// Also print the count from every starting position.
+    #[arg(long, requires = "sweep")]
+    distribution: bool,
+    /// Turn a lock with wheels of these sizes instead, following instructions like "2:L35" that turn wheel 2.
+    /// The first wheel starts at 50 and the others at 0.
+    #[arg(
+        long,
+        value_name = "SIZE",
+        value_delimiter = ',',
+        value_parser = clap::value_parser!(i64).range(1..),
+        conflicts_with_all = ["targets", "trace", "sweep"]
+    )]
+    wheels: Vec<i64>,
+    /// Carry from each wheel into the next like an odometer.
+    #[arg(long, requires = "wheels")]
+    carry: bool,
+    /// Count while reading the input a line at a time, in constant memory, and print the throughput.
+    #[arg(long, conflicts_with_all = ["targets", "trace", "sweep", "wheels"])]
+    stream: bool,
+    /// Read the input as a rotation script, e.g. "3x(L20, R5); R250", and count it without unrolling repeats.
+    #[arg(long, conflicts_with_all = ["targets", "trace", "sweep", "wheels", "stream"])]
+    script: bool,
+    /// Animate the dial turning click by click, counting in --mode (default "passes").
+    #[arg(long, conflicts_with_all = ["targets", "trace", "sweep", "wheels", "stream", "script"])]
+    visualize: bool,
+    /// Frames to animate per second.
+    #[arg(long, value_name = "FRAMES", default_value_t = 20.0, requires = "visualize")]
+    speed: f64,
+    /// Write every frame to a numbered text file in this directory instead of animating.
+    #[arg(long, value_name = "DIR", requires = "visualize")]
+    frames: Option<PathBuf>,
+    /// Only animate the first N rotations.
+    #[arg(long, value_name = "N", requires = "visualize")]
+    rotations: Option<usize>,
+    /// Show at most this many evenly spaced clicks of each rotation.
+    #[arg(
+        long,
+        value_name = "CLICKS",
+        default_value_t = DIAL_POSITIONS,
+        value_parser = clap::value_parser!(i64).range(1..),
+        requires = "visualize"
+    )]
+    max_clicks: i64,
+    /// Refuse to animate or write more frames than this.
+    #[arg(long, value_name = "FRAMES", default_value_t = 10_000, requires = "visualize")]
+    max_frames: i64,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TraceFormat {
+    Table,
+    /// One JSON object per line.
+    Json,
+}
+
+fn main() -> ExitCode {
+    report(run(&Args::parse()))
+}
+
+fn print_trace(rotations: &[Rotation], mode: CountMode, format: TraceFormat, clicks: bool) {
+    let dial = Dial::new(DIAL_POSITIONS, START_POSITION);
+    let mut trace = Trace::new(dial, rotations.iter().copied(), mode);
+    if clicks {
+        trace = trace.with_clicks();
+    }
+    if let TraceFormat::Table = format {
+        println!("{}", TraceEvent::TABLE_HEADER);
+    }
+    for event in trace {
+        match format {
+            TraceFormat::Table => println!("{}", event.table_row()),
+            TraceFormat::Json => println!("{}", event.json_line()),
+        }
+    }
+}
+
+fn print_sweep(rotations: &[Rotation], mode: CountMode, distribution: bool) -> Result<(), Overflow> {
+    let sweep = Sweep::new(DIAL_POSITIONS, rotations.iter().copied(), mode)?;
+    let (best, most) = sweep.best();
+    let (worst, fewest) = sweep.worst();
+    println!("Best start ({mode}): {best} with {most} occurences of 0");
+    println!("Worst start ({mode}): {worst} with {fewest} occurences of 0");
+    if distribution {
+        println!("start   hits");
+        for (start, hits) in sweep.hits().iter().enumerate() {
+            println!("{start:>5}  {hits:>5}");
+        }
+    }
+    Ok(())
+}
+
+fn run_lock(args: &Args, modes: &[CountMode]) -> Result<(), Box<dyn Error>> {
+    let source = InputLocator::new(Day1::YEAR, Day1::DAY)
+        .with_input(args.input.as_deref())
+        .locate()?;
+    let instructions =
+        parse_wheel_rotations(&source.read()?).map_err(|err| err.with_file(source.to_string()))?;
+    for &mode in modes {
+        let wheels = args.wheels.iter().enumerate().map(|(index, &size)| {
+            let start = if index == 0 { START_POSITION.rem_euclid(size) } else { 0 };
+            Dial::new(size, start)
+        });
+        let mut lock = Lock::new(wheels.collect(), mode);
+        if args.carry {
+            lock = lock.with_carry();
+        }
+        for (index, &instruction) in instructions.iter().enumerate() {
+            lock.apply(instruction)
+                .map_err(|err| format!("instruction {} ({instruction}): {err}", index + 1))?;
+        }
+        let combination: Vec<String> = lock.combination().iter().map(i64::to_string).collect();
+        println!("Combination ({mode}): {}", combination.join("-"));
+        for (wheel, hits) in lock.hits().iter().enumerate() {
+            println!("Number of occurences of 0 on wheel {} ({mode}): {hits}", wheel + 1);
+        }
+    }
+    Ok(())
+}
+
+fn run_stream(args: &Args, modes: &[CountMode]) -> Result<(), Box<dyn Error>> {
+    let source = InputLocator::new(Day1::YEAR, Day1::DAY)
+        .with_input(args.input.as_deref())
+        .locate()?;
+    let started = Instant::now();
+    let counts = count_streamed(source.open()?, START_POSITION).map_err(|err| match err {
+        StreamError::Parse(err) => err.with_file(source.to_string()).to_string(),
+        StreamError::Read(err) => format!("failed to read {source}: {err}"),
+        StreamError::Overflow(err) => format!("{source}: {err}"),
+    })?;
+    let elapsed = started.elapsed();
+    for &mode in modes {
+        println!("Number of occurences of 0 ({mode}): {}", counts.hits(mode));
+    }
+    println!(
+        "Streamed {} rotations ({} bytes) in {elapsed:.2?}, {:.2} MB/s",
+        counts.rotations,
+        counts.bytes,
+        counts.bytes as f64 / elapsed.as_secs_f64() / 1e6
+    );
+    Ok(())
+}
+
+fn run_script(args: &Args, modes: &[CountMode]) -> Result<(), Box<dyn Error>> {
+    let source = InputLocator::new(Day1::YEAR, Day1::DAY)
+        .with_input(args.input.as_deref())
+        .locate()?;
+    let script: Script = source
+        .read()?
+        .parse()
+        .map_err(|err: ParseError| err.with_file(source.to_string()))?;
+    for &mode in modes {
+        let hits = script.count(START_POSITION, mode)?;
+        println!("Number of occurences of 0 ({mode}): {hits}");
+    }
+    Ok(())
+}
+
+fn visualize(rotations: &[Rotation], args: &Args) -> Result<(), Box<dyn Error>> {
+    if !(args.speed > 0.0 && args.speed.is_finite()) {
+        let speed = args.speed;
+        return Err(format!("the speed must be a positive number of frames per second, got {speed}").into());
+    }
+    let rotations = &rotations[..args.rotations.unwrap_or(rotations.len()).min(rotations.len())];
+    let mode = args.mode.unwrap_or(CountMode::PassesTarget);
+    let frames = Animation::new(Dial::new(DIAL_POSITIONS, START_POSITION), rotations.iter().copied(), mode)
+        .with_max_clicks(args.max_clicks);
+    let count = rotations.iter().map(|&rotation| frames.frames_for(rotation)).fold(0, i64::saturating_add);
+    if count > args.max_frames {
+        let max = args.max_frames;
+        let advice = "choose fewer with --rotations or --max-clicks, or raise --max-frames";
+        return Err(format!("the animation would take {count} frames, more than {max}; {advice}").into());
+    }
+    let ring = Ring::new(DIAL_POSITIONS, mode);
+    if let Some(dir) = &args.frames {
+        let written = dump_frames(dir, &ring, frames).map_err(|err| format!("{}: {err}", dir.display()))?;
+        println!("Wrote {written} frames to {}", dir.display());
+        return Ok(());
+    }
+    let ring = if io::stdout().is_terminal() { ring.with_color() } else { ring };
+    let delay = Duration::from_secs_f64(1.0 / args.speed);
+    let mut stdout = io::stdout().lock();
+    for frame in frames {
+        // Move to the top left and clear the screen before drawing each frame.
+        write!(stdout, "\x1b[H\x1b[2J{}", ring.render(&frame))?;
+        stdout.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let modes = match args.mode {
+        Some(mode) => vec![mode],
+        None => CountMode::ALL.to_vec(),
+    };
+    if !args.wheels.is_empty() {
+        return run_lock(args, &modes);
+    }
+    if args.stream {
+        return run_stream(args, &modes);
+    }
+    if args.script {
+        return run_script(args, &modes);
+    }
+    let parsed_rotations = load_input_from::<Day1>(args.input.as_deref())?;
+    if let Some(format) = args.trace {
+        print_trace(&parsed_rotations, args.mode.unwrap_or(CountMode::PassesTarget), format, args.clicks);
+        return Ok(());
+    }
+    if args.visualize {
+        return visualize(&parsed_rotations, args);
+    }
+    let targets = if args.targets.is_empty() { &[0][..] } else { &args.targets };
+    if args.sweep {
+        for mode in modes {
+            print_sweep(&parsed_rotations, mode, args.distribution)?;
+        }
+        return Ok(());
+    }
+    for mode in modes {
+        let rotations = parsed_rotations.iter().copied();
+        let counts = count_target_occurrences(rotations, START_POSITION, targets, mode)?;
+        for (target, num_occurences) in counts {
+            println!("Number of occurences of {target} ({mode}): {num_occurences}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use crate::rotation::{Direction, Rotation};
+
+/// A circular dial numbered `0..positions`, currently pointing at `position`.
+///
+/// Turning right counts up and turning left counts down, wrapping around at 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    positions: i64,
+    position: i64,
+}
+
+/// Where a single rotation left the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub position: i64,
+    /// Number of times the rotation passed or landed on 0.
+    pub zero_crossings: i64,
+}
+
+impl Dial {
+    /// A dial with `positions` positions pointing at `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `positions` is not positive or `start` is not on the dial.
+    pub fn new(positions: i64, start: i64) -> Self {
+        assert!(positions > 0, "a dial needs at least one position, got {positions}");
+        assert!(
+            (0..positions).contains(&start),
+            "start position {start} is not on a dial of {positions} positions"
+        );
+        Self {
+            positions,
+            position: start,
+        }
+    }
+
+    pub fn positions(&self) -> i64 {
+        self.positions
+    }
+
+    pub fn position(&self) -> i64 {
+        self.position
+    }
+
+    /// The same dial renumbered so that `target` is 0, which lets [`Dial::rotate`] count crossings of any
+    /// position.
+    ///
+    /// # Panics
+    ///
+    /// If `target` is not on the dial.
+    pub fn relative_to(&self, target: i64) -> Self {
+        assert!(
+            (0..self.positions).contains(&target),
+            "target {target} is not on a dial of {} positions",
+            self.positions
+        );
+        Self {
+            positions: self.positions,
+            position: (self.position - target).rem_euclid(self.positions),
+        }
+    }
+
+    /// Turns the dial by `rotation`.
+    ///
+    /// Works from whole laps of the dial and the clicks left over, so nothing overflows however many
+    /// clicks the rotation has.
+    pub fn rotate(&mut self, rotation: Rotation) -> Turn {
+        let n = self.positions;
+        let (laps, remainder) = (rotation.steps / n, rotation.steps % n);
+        // Clicks until the dial next reaches 0, a whole lap when it starts there.
+        let to_zero = match rotation.direction {
+            Direction::Left if self.position == 0 => n,
+            Direction::Left => self.position,
+            Direction::Right => n - self.position,
+        };
+        self.position = match rotation.direction {
+            Direction::Left => (self.position - remainder).rem_euclid(n),
+            Direction::Right => (self.position - (n - remainder)).rem_euclid(n),
+        };
+        Turn {
+            position: self.position,
+            zero_crossings: laps + i64::from(remainder >= to_zero),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_reports_position_and_crossings() {
+        let mut dial = Dial::new(100, 50);
+        assert_eq!(
+            dial.rotate(Rotation::left(68)),
+            Turn {
+                position: 82,
+                zero_crossings: 1
+            }
+        );
+        assert_eq!(
+            dial.rotate(Rotation::right(18)),
+            Turn {
+                position: 0,
+                zero_crossings: 1
+            }
+        );
+        // Leaving 0 does not count as passing it again.
+        assert_eq!(dial.rotate(Rotation::left(5)).zero_crossings, 0);
+        assert_eq!(dial.position(), 95);
+    }
+
+    #[test]
+    fn test_small_dial_wraps_several_times() {
+        let mut dial = Dial::new(10, 3);
+        assert_eq!(
+            dial.rotate(Rotation::right(27)),
+            Turn {
+                position: 0,
+                zero_crossings: 3
+            }
+        );
+        assert_eq!(
+            dial.rotate(Rotation::left(25)),
+            Turn {
+                position: 5,
+                zero_crossings: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_single_position_dial_is_always_at_zero() {
+        let mut dial = Dial::new(1, 0);
+        assert_eq!(dial.rotate(Rotation::right(4)).zero_crossings, 4);
+        assert_eq!(dial.rotate(Rotation::left(3)).zero_crossings, 3);
+        assert_eq!(dial.position(), 0);
+    }
+
+    #[test]
+    fn test_relative_dial_counts_crossings_of_target() {
+        let dial = Dial::new(10, 3);
+        let mut relative = dial.relative_to(7);
+        assert_eq!(relative.position(), 6);
+        // 3 -> 7 after 4 clicks and again after 14, ending on 9.
+        assert_eq!(
+            relative.rotate(Rotation::right(16)),
+            Turn {
+                position: 2,
+                zero_crossings: 2
+            }
+        );
+        let mut relative = dial.relative_to(1);
+        assert_eq!(relative.rotate(Rotation::left(2)).position, 0);
+    }
+
+    #[test]
+    fn test_billions_of_steps_do_not_overflow() {
+        let mut dial = Dial::new(100, 99);
+        assert_eq!(
+            dial.rotate(Rotation::right(4_000_000_001)),
+            Turn {
+                position: 0,
+                zero_crossings: 40_000_001
+            }
+        );
+        assert_eq!(
+            dial.rotate(Rotation::left(i64::MAX)),
+            Turn {
+                position: 93,
+                zero_crossings: 92_233_720_368_547_758
+            }
+        );
+        let mut dial = Dial::new(i64::MAX, i64::MAX - 1);
+        assert_eq!(dial.rotate(Rotation::right(i64::MAX)).zero_crossings, 1);
+        assert_eq!(dial.position(), i64::MAX - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "not on a dial")]
+    fn test_start_must_be_on_the_dial() {
+        Dial::new(10, 10);
+    }
+}
@@ -0,0 +1,381 @@
+mod dial;
+mod lock;
+mod reference;
+mod rotation;
+mod script;
+mod segment_tree;
+mod stream;
+mod sweep;
+mod trace;
+mod visualize;
+
+pub use dial::{Dial, Turn};
+pub use lock::{parse_wheel_rotations, Lock, LockError, LockState, WheelRotation};
+pub use reference::count_by_clicks;
+pub use rotation::{Direction, Rotation};
+pub use script::{Script, ScriptRotations};
+pub use segment_tree::{RotationTree, Segment};
+pub use stream::{count_streamed, RotationReader, StreamCounts, StreamError};
+pub use sweep::Sweep;
+pub use trace::{Trace, TraceEvent};
+pub use visualize::{dump_frames, Animation, Frame, Ring};
+
+use aoc_common::input::parse_lines_with;
+use aoc_common::{Error, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Number of positions on the safe's dial.
+pub const DIAL_POSITIONS: i64 = 100;
+
+/// Position the safe's dial starts at.
+pub const START_POSITION: i64 = 50;
+
+/// Which visits of the target position are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountMode {
+    /// Only rotations that finish on the target.
+    EndsOnTarget,
+    /// Every click that lands on the target, including those in the middle of a rotation.
+    PassesTarget,
+}
+
+impl CountMode {
+    pub const ALL: [CountMode; 2] = [CountMode::EndsOnTarget, CountMode::PassesTarget];
+
+    /// Number of times `turn` counts as reaching 0 in this mode.
+    pub fn hits(self, turn: Turn) -> i64 {
+        match self {
+            CountMode::EndsOnTarget => i64::from(turn.position == 0),
+            CountMode::PassesTarget => turn.zero_crossings,
+        }
+    }
+}
+
+impl FromStr for CountMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ends" => Ok(CountMode::EndsOnTarget),
+            "passes" => Ok(CountMode::PassesTarget),
+            _ => Err(format!("unknown count mode {s:?}, expected \"ends\" or \"passes\"")),
+        }
+    }
+}
+
+impl Display for CountMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountMode::EndsOnTarget => write!(f, "ends"),
+            CountMode::PassesTarget => write!(f, "passes"),
+        }
+    }
+}
+
+/// A count of hits too large for an `i64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the number of hits does not fit in a 64-bit integer")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Adds `hits` to a running `total`, failing rather than wrapping around.
+pub fn add_hits(total: i64, hits: i64) -> Result<i64, Overflow> {
+    total.checked_add(hits).ok_or(Overflow)
+}
+
+/// Parses rotation lines e.g. "L68", skipping blank lines.
+pub fn parse_rotations(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    parse_lines_with(input, str::parse)
+}
+
+/// Counts the number of times position 0 of the safe's dial is reached given rotations and an initial position.
+///
+/// # Arguments
+///
+/// * `rotations` - Rotations in order e.g. [L50, R50]
+/// * `initial_position` - Starting position on the dial
+/// * `mode` - Whether to count only rotations ending on 0, or every pass through 0
+///
+/// # Returns
+///
+/// Number of times position 0 is reached, or [`Overflow`] if that does not fit in an `i64`.
+pub fn count_zero_occurrences(
+    rotations: impl IntoIterator<Item = Rotation>,
+    initial_position: i64,
+    mode: CountMode,
+) -> Result<i64, Overflow> {
+    let mut dial = Dial::new(DIAL_POSITIONS, initial_position);
+    rotations
+        .into_iter()
+        .try_fold(0, |total, rotation| add_hits(total, mode.hits(dial.rotate(rotation))))
+}
+
+/// Counts the number of times each of `targets` is reached given rotations and an initial position, turning
+/// the dial only once.
+///
+/// # Arguments
+///
+/// * `rotations` - Rotations in order e.g. [L50, R50]
+/// * `initial_position` - Starting position on the dial
+/// * `targets` - Positions to count, duplicates are counted once
+/// * `mode` - Whether to count only rotations ending on a target, or every pass through it
+///
+/// # Returns
+///
+/// Number of times each target is reached, keyed by target, or [`Overflow`] if any count does not fit
+/// in an `i64`.
+///
+/// # Panics
+///
+/// If any target is not on the dial.
+pub fn count_target_occurrences(
+    rotations: impl IntoIterator<Item = Rotation>,
+    initial_position: i64,
+    targets: &[i64],
+    mode: CountMode,
+) -> Result<BTreeMap<i64, i64>, Overflow> {
+    let mut dial = Dial::new(DIAL_POSITIONS, initial_position);
+    let mut counts: BTreeMap<i64, i64> = targets.iter().map(|&target| (target, 0)).collect();
+    for rotation in rotations {
+        for (&target, count) in counts.iter_mut() {
+            *count = add_hits(*count, mode.hits(dial.relative_to(target).rotate(rotation)))?;
+        }
+        dial.rotate(rotation);
+    }
+    Ok(counts)
+}
+
+/// The safe's dial, which starts pointing at [`START_POSITION`].
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+    type Input = Vec<Rotation>;
+
+    /// Parses the rotations, rejecting any whose hits might not fit in an `i64`.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rotations = parse_rotations(input)?;
+        // A rotation reaches 0 at most once per click, or once if it has none, so the clicks plus the number
+        // of rotations bound every count.
+        rotations
+            .iter()
+            .try_fold(0_i64, |total, rotation| total.checked_add(rotation.steps)?.checked_add(1))
+            .ok_or_else(|| ParseError::new("too many clicks in total to count in 64 bits"))?;
+        Ok(rotations)
+    }
+
+    /// The number of rotations that leave the dial at 0.
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(count_zero_occurrences(input.iter().copied(), START_POSITION, CountMode::EndsOnTarget)
+            .expect("parse rejects rotations that could overflow"))
+    }
+
+    /// The number of clicks that leave the dial at 0.
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(count_zero_occurrences(input.iter().copied(), START_POSITION, CountMode::PassesTarget)
+            .expect("parse rejects rotations that could overflow"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rotations from (direction, steps) pairs e.g. [('L', 50), ('R', 50)].
+    fn rotations(pairs: Vec<(char, i64)>) -> Vec<Rotation> {
+        pairs
+            .into_iter()
+            .map(|(direction, steps)| Rotation {
+                direction: Direction::try_from(direction).unwrap(),
+                steps,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_rotations_reports_bad_lines() {
+        assert_eq!(parse_rotations("L68\n\nR5\n"), Ok(rotations(vec![('L', 68), ('R', 5)])));
+        let err = parse_rotations("L68\nR5x\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_deref()), (Some(2), Some(3), Some("x")));
+        let err = parse_rotations("L68\n\n  X5\n").unwrap_err();
+        assert_eq!(err.to_string(), "3:3: unknown direction, expected L or R: \"X\"");
+    }
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    #[test]
+    fn test_example_in_both_modes() {
+        let rotations = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&rotations).unwrap().to_string(), "3");
+        assert_eq!(Day1::part2(&rotations).unwrap().to_string(), "6");
+    }
+
+    #[test]
+    fn test_fixtures_in_both_modes() {
+        // (rotations, landing on zero, passing zero), all starting at 50.
+        let fixtures = [
+            (vec![('L', 50), ('R', 50)], 1, 1),
+            (vec![('L', 50), ('L', 50)], 1, 1),
+            (vec![('R', 50), ('L', 50)], 1, 1),
+            (vec![('R', 50), ('R', 50)], 1, 1),
+            (vec![('L', 150), ('L', 50)], 1, 2),
+            (vec![('L', 150), ('R', 50)], 1, 2),
+            (vec![('R', 150), ('L', 50)], 1, 2),
+            (vec![('R', 150), ('R', 50)], 1, 2),
+            (vec![('R', 250), ('R', 50)], 1, 3),
+            (vec![('R', 250), ('L', 100)], 2, 4),
+            (vec![('R', 49), ('L', 98)], 0, 0),
+        ];
+        for (rotations, ends, passes) in fixtures {
+            assert_eq!(
+                count_zero_occurrences(self::rotations(rotations.clone()), 50, CountMode::EndsOnTarget),
+                Ok(ends),
+                "{rotations:?}"
+            );
+            assert_eq!(
+                count_zero_occurrences(self::rotations(rotations.clone()), 50, CountMode::PassesTarget),
+                Ok(passes),
+                "{rotations:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_target_occurrences() {
+        // 50 -> 82 -> 52 -> 0 -> 95 -> 55 -> 0 -> 99 -> 0 -> 14 -> 32.
+        let rotations = Day1::parse(EXAMPLE).unwrap();
+        let count =
+            |targets: &[i64], mode| count_target_occurrences(rotations.iter().copied(), 50, targets, mode);
+        assert_eq!(
+            count(&[0, 55, 99, 1], CountMode::EndsOnTarget),
+            Ok(BTreeMap::from([(0, 3), (1, 0), (55, 1), (99, 1)]))
+        );
+        assert_eq!(count(&[0, 0, 50], CountMode::PassesTarget), Ok(BTreeMap::from([(0, 6), (50, 4)])));
+    }
+
+    #[test]
+    fn test_target_zero_matches_count_zero_occurrences() {
+        let rotations = self::rotations(vec![('R', 250), ('L', 100), ('L', 49), ('R', 1)]);
+        for mode in CountMode::ALL {
+            let counts = count_target_occurrences(rotations.iter().copied(), 50, &[0], mode).unwrap();
+            assert_eq!(Ok(counts[&0]), count_zero_occurrences(rotations.iter().copied(), 50, mode));
+        }
+    }
+
+    #[test]
+    fn test_billions_of_steps() {
+        let rotations = [
+            Rotation::right(2_000_000_050),
+            Rotation::left(3_000_000_000),
+            Rotation::right(7_500_000_001),
+        ];
+        // 50 -> 0 -> 0 -> 1
+        let count = |mode| count_zero_occurrences(rotations.iter().copied(), 50, mode);
+        assert_eq!(count(CountMode::EndsOnTarget), Ok(2));
+        assert_eq!(count(CountMode::PassesTarget), Ok(20_000_001 + 30_000_000 + 75_000_000));
+        let input = "R2000000050\nL3000000000\nR7500000001\n";
+        assert_eq!(Day1::part2(&Day1::parse(input).unwrap()).unwrap().to_string(), "125000001");
+    }
+
+    #[test]
+    fn test_overflowing_counts_are_errors() {
+        let rotations = [Rotation::right(i64::MAX), Rotation::right(i64::MAX)];
+        let count = count_zero_occurrences(rotations, 0, CountMode::PassesTarget);
+        assert_eq!(count, Ok(184_467_440_737_095_516));
+        let rotations = vec![Rotation::left(i64::MAX); 101];
+        assert_eq!(count_zero_occurrences(rotations.clone(), 0, CountMode::PassesTarget), Err(Overflow));
+        assert_eq!(count_target_occurrences(rotations, 0, &[7], CountMode::PassesTarget), Err(Overflow));
+
+        let err = Day1::parse("R9223372036854775807\nL1\n").unwrap_err();
+        assert_eq!(err.message, "too many clicks in total to count in 64 bits");
+        assert!(Day1::parse("R9223372036854775806\n").is_ok());
+    }
+
+    #[test]
+    fn test_count_mode_from_str() {
+        for mode in CountMode::ALL {
+            assert_eq!(mode.to_string().parse(), Ok(mode));
+        }
+        assert!("sometimes".parse::<CountMode>().is_err());
+    }
+
+    #[test]
+    fn test_l50_r50_equals_1() {
+        // Starts at 50; L50,R50
+        let rotations = vec![('L', 50), ('R', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(1));
+    }
+
+    #[test]
+    fn test_l50_l50_equals_1() {
+        // Starts at 50; L50,L50
+        let rotations = vec![('L', 50), ('L', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(1));
+    }
+
+    #[test]
+    fn test_r50_l50_equals_1() {
+        // Starts at 50; R50,L50
+        let rotations = vec![('R', 50), ('L', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(1));
+    }
+
+    #[test]
+    fn test_r50_r50_equals_1() {
+        // Starts at 50; R50,R50
+        let rotations = vec![('R', 50), ('R', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(1));
+    }
+
+    #[test]
+    fn test_l150_l50_equals_2() {
+        // Starts at 50; L150,L50
+        let rotations = vec![('L', 150), ('L', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(2));
+    }
+
+    #[test]
+    fn test_l150_r50_equals_2() {
+        // Starts at 50; L150,R50
+        let rotations = vec![('L', 150), ('R', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(2));
+    }
+
+    #[test]
+    fn test_r150_l50_equals_2() {
+        // Starts at 50; R150,L50
+        let rotations = vec![('R', 150), ('L', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(2));
+    }
+
+    #[test]
+    fn test_r150_r50_equals_2() {
+        // Starts at 50; R150,R50
+        let rotations = vec![('R', 150), ('R', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(2));
+    }
+
+    #[test]
+    fn test_r250_r50_equals_3() {
+        // Starts at 50; R150,R50
+        let rotations = vec![('R', 250), ('R', 50)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(3));
+    }
+
+    #[test]
+    fn test_r250_l100_equals_4() {
+        // Starts at 50; R150,L100
+        let rotations = vec![('R', 250), ('L', 100)];
+        assert_eq!(count_zero_occurrences(self::rotations(rotations), 50, CountMode::PassesTarget), Ok(4));
+    }
+}
+
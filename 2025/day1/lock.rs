@@ -0,0 +1,296 @@
+use crate::{add_hits, CountMode, Dial, Direction, Overflow, Rotation};
+use aoc_common::error::column_of;
+use aoc_common::input::parse_lines_with;
+use aoc_common::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A rotation of one wheel of a [`Lock`], written e.g. "2:L35", or just "L35" for the first wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WheelRotation {
+    /// Index of the wheel turned, counting from 0 although wheels are written counting from 1.
+    pub wheel: usize,
+    pub rotation: Rotation,
+}
+
+impl FromStr for WheelRotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((wheel, rotation)) = s.split_once(':') else {
+            return Ok(Self {
+                wheel: 0,
+                rotation: s.parse()?,
+            });
+        };
+        let number = wheel
+            .parse::<usize>()
+            .map_err(|_| ParseError::invalid(s, wheel, "invalid wheel number"))?;
+        if number == 0 {
+            return Err(ParseError::invalid(s, wheel, "wheels are numbered from 1"));
+        }
+        let rotation = rotation
+            .parse()
+            .map_err(|err: ParseError| err.shift_columns(column_of(s, rotation) - 1))?;
+        Ok(Self {
+            wheel: number - 1,
+            rotation,
+        })
+    }
+}
+
+impl fmt::Display for WheelRotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.wheel + 1, self.rotation)
+    }
+}
+
+/// Parses wheel rotation lines e.g. "2:L35", skipping blank lines.
+pub fn parse_wheel_rotations(input: &str) -> Result<Vec<WheelRotation>, ParseError> {
+    parse_lines_with(input, str::parse)
+}
+
+/// Why a [`Lock`] could not follow an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockError {
+    /// The instruction turns a wheel past the last one, both counting from 0.
+    NoSuchWheel { wheel: usize, wheels: usize },
+    Overflow(Overflow),
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LockError::NoSuchWheel { wheel, wheels } => {
+                write!(f, "there is no wheel {}, the lock has {wheels}", wheel + 1)
+            }
+            LockError::Overflow(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for LockError {}
+
+impl From<Overflow> for LockError {
+    fn from(err: Overflow) -> Self {
+        LockError::Overflow(err)
+    }
+}
+
+/// The positions of a lock's wheels and how often each has reached 0, after some instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockState {
+    pub combination: Vec<i64>,
+    pub hits: Vec<i64>,
+}
+
+/// A combination lock made of several [`Dial`]s, each of its own size.
+///
+/// With carrying, the wheels work like an odometer with the first wheel as the least significant: every
+/// time a wheel turns right from its last position onto 0 the next wheel turns right one click, and
+/// every time it turns left from 0 onto its last position the next wheel turns left one click. Carries
+/// out of the last wheel are lost.
+#[derive(Debug, Clone)]
+pub struct Lock {
+    wheels: Vec<Dial>,
+    mode: CountMode,
+    carry: bool,
+    hits: Vec<i64>,
+}
+
+impl Lock {
+    /// A lock of `wheels`, without carrying, counting hits on 0 of each wheel in `mode`.
+    ///
+    /// # Panics
+    ///
+    /// If there are no wheels.
+    pub fn new(wheels: Vec<Dial>, mode: CountMode) -> Self {
+        assert!(!wheels.is_empty(), "a lock needs at least one wheel");
+        let hits = vec![0; wheels.len()];
+        Self {
+            wheels,
+            mode,
+            carry: false,
+            hits,
+        }
+    }
+
+    /// Carries from each wheel into the next like an odometer.
+    pub fn with_carry(mut self) -> Self {
+        self.carry = true;
+        self
+    }
+
+    pub fn wheels(&self) -> &[Dial] {
+        &self.wheels
+    }
+
+    /// Position of every wheel, first wheel first.
+    pub fn combination(&self) -> Vec<i64> {
+        self.wheels.iter().map(Dial::position).collect()
+    }
+
+    /// Number of hits on 0 of every wheel so far.
+    pub fn hits(&self) -> &[i64] {
+        &self.hits
+    }
+
+    pub fn state(&self) -> LockState {
+        LockState {
+            combination: self.combination(),
+            hits: self.hits.clone(),
+        }
+    }
+
+    /// Turns a wheel, and the wheels it carries into, leaving the lock as it was if that fails.
+    pub fn apply(&mut self, instruction: WheelRotation) -> Result<LockState, LockError> {
+        if instruction.wheel >= self.wheels.len() {
+            return Err(LockError::NoSuchWheel {
+                wheel: instruction.wheel,
+                wheels: self.wheels.len(),
+            });
+        }
+        let (mut wheels, mut hits) = (self.wheels.clone(), self.hits.clone());
+        let (mut wheel, mut rotation) = (instruction.wheel, instruction.rotation);
+        loop {
+            let dial = &mut wheels[wheel];
+            // A left turn borrows on every click onto the last position, which can only come from 0.
+            let borrows = dial.relative_to(dial.positions() - 1).rotate(rotation).zero_crossings;
+            let turn = dial.rotate(rotation);
+            hits[wheel] = add_hits(hits[wheel], self.mode.hits(turn))?;
+            let carries = match rotation.direction {
+                Direction::Left => borrows,
+                Direction::Right => turn.zero_crossings,
+            };
+            if !self.carry || carries == 0 || wheel + 1 == wheels.len() {
+                break;
+            }
+            wheel += 1;
+            rotation.steps = carries;
+        }
+        self.wheels = wheels;
+        self.hits = hits;
+        Ok(self.state())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, parse_rotations, DIAL_POSITIONS};
+
+    fn lock(sizes: &[i64]) -> Lock {
+        Lock::new(sizes.iter().map(|&size| Dial::new(size, 0)).collect(), CountMode::PassesTarget)
+    }
+
+    fn instruction(line: &str) -> WheelRotation {
+        line.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_wheel_rotations() {
+        let instructions = parse_wheel_rotations("2:L35\nR5\n\n10:R0\n").unwrap();
+        assert_eq!(
+            instructions,
+            vec![
+                WheelRotation {
+                    wheel: 1,
+                    rotation: Rotation::left(35)
+                },
+                WheelRotation {
+                    wheel: 0,
+                    rotation: Rotation::right(5)
+                },
+                WheelRotation {
+                    wheel: 9,
+                    rotation: Rotation::right(0)
+                },
+            ]
+        );
+        assert_eq!(instructions[1].to_string(), "1:R5");
+
+        let err = parse_wheel_rotations("R5\n  0:L5\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:3: wheels are numbered from 1: \"0\"");
+        let err = parse_wheel_rotations("x:L5").unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (Some(1), "invalid wheel number"));
+        let err = parse_wheel_rotations("12:U5").unwrap_err();
+        assert_eq!((err.column, err.text.as_deref()), (Some(4), Some("U")));
+        let err = parse_wheel_rotations("3:R5x").unwrap_err();
+        assert_eq!((err.column, err.text.as_deref()), (Some(5), Some("x")));
+    }
+
+    #[test]
+    fn test_single_wheel_matches_the_puzzle() {
+        let rotations = parse_rotations("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+        for mode in CountMode::ALL {
+            let mut lock = Lock::new(vec![Dial::new(DIAL_POSITIONS, 50)], mode).with_carry();
+            for &rotation in &rotations {
+                lock.apply(WheelRotation { wheel: 0, rotation }).unwrap();
+            }
+            assert_eq!(Ok(lock.hits()[0]), count_zero_occurrences(rotations.iter().copied(), 50, mode));
+            assert_eq!(lock.combination(), [32]);
+        }
+    }
+
+    #[test]
+    fn test_wheels_turn_independently_without_carry() {
+        let mut lock = lock(&[10, 6]);
+        let state = lock.apply(instruction("2:L13")).unwrap();
+        assert_eq!(
+            state,
+            LockState {
+                combination: vec![0, 5],
+                hits: vec![0, 2]
+            }
+        );
+        let state = lock.apply(instruction("R25")).unwrap();
+        assert_eq!(
+            state,
+            LockState {
+                combination: vec![5, 5],
+                hits: vec![2, 2]
+            }
+        );
+    }
+
+    #[test]
+    fn test_carry_works_like_an_odometer() {
+        let mut lock = lock(&[10, 10, 10]).with_carry();
+        let state = lock.apply(instruction("R1234")).unwrap();
+        assert_eq!(
+            state,
+            LockState {
+                combination: vec![4, 3, 2],
+                hits: vec![123, 12, 1]
+            }
+        );
+        let state = lock.apply(instruction("L235")).unwrap();
+        assert_eq!(
+            state,
+            LockState {
+                combination: vec![9, 9, 9],
+                hits: vec![147, 15, 2]
+            }
+        );
+        // Carrying out of the last wheel is lost.
+        assert_eq!(lock.apply(instruction("1:R1")).unwrap().combination, [0, 0, 0]);
+        assert_eq!(lock.apply(instruction("2:L1")).unwrap().combination, [0, 9, 9]);
+    }
+
+    #[test]
+    fn test_failed_instructions_leave_the_lock_unchanged() {
+        let mut lock = lock(&[1, 1]).with_carry();
+        lock.apply(instruction("R9223372036854775807")).unwrap();
+        assert_eq!(lock.hits(), [i64::MAX, i64::MAX]);
+        assert_eq!(lock.apply(instruction("2:R1")), Err(LockError::Overflow(Overflow)));
+        assert_eq!(
+            lock.apply(instruction("3:R1")),
+            Err(LockError::NoSuchWheel { wheel: 2, wheels: 2 })
+        );
+        assert_eq!(
+            LockError::NoSuchWheel { wheel: 2, wheels: 2 }.to_string(),
+            "there is no wheel 3, the lock has 2"
+        );
+        assert_eq!(lock.hits(), [i64::MAX, i64::MAX]);
+    }
+}
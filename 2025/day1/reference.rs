@@ -0,0 +1,119 @@
+use crate::{CountMode, Direction, Rotation};
+
+/// Counts hits on 0 by turning a dial of `positions` positions one click at a time, as a slow but obviously
+/// correct reference for the arithmetic in [`crate::Dial::rotate`] and everything built on it.
+///
+/// # Panics
+///
+/// If `positions` is not positive or `initial_position` is not on the dial.
+pub fn count_by_clicks(
+    positions: i64,
+    initial_position: i64,
+    rotations: impl IntoIterator<Item = Rotation>,
+    mode: CountMode,
+) -> i64 {
+    assert!(positions > 0, "a dial needs at least one position, got {positions}");
+    assert!(
+        (0..positions).contains(&initial_position),
+        "start position {initial_position} is not on the dial"
+    );
+    let mut position = initial_position;
+    let mut hits = 0;
+    for rotation in rotations {
+        for _ in 0..rotation.steps {
+            position = match rotation.direction {
+                Direction::Left if position == 0 => positions - 1,
+                Direction::Left => position - 1,
+                Direction::Right if position == positions - 1 => 0,
+                Direction::Right => position + 1,
+            };
+            if mode == CountMode::PassesTarget && position == 0 {
+                hits += 1;
+            }
+        }
+        if mode == CountMode::EndsOnTarget && position == 0 {
+            hits += 1;
+        }
+    }
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, Dial, Segment, Sweep, DIAL_POSITIONS};
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn rotation(max_steps: i64) -> impl Strategy<Value = Rotation> {
+        (any::<bool>(), 0..=max_steps).prop_map(|(left, steps)| {
+            if left {
+                Rotation::left(steps)
+            } else {
+                Rotation::right(steps)
+            }
+        })
+    }
+
+    /// A dial size of up to `max_positions`, a start on it, and rotations of up to a few laps each.
+    fn dial_and_rotations(
+        max_positions: i64,
+        max_rotations: usize,
+    ) -> impl Strategy<Value = (i64, i64, Vec<Rotation>)> {
+        (1..=max_positions).prop_flat_map(move |positions| {
+            (Just(positions), 0..positions, vec(rotation(positions * 4), 0..=max_rotations))
+        })
+    }
+
+    fn mode() -> impl Strategy<Value = CountMode> {
+        prop::sample::select(CountMode::ALL.to_vec())
+    }
+
+    #[test]
+    fn test_clicks_match_the_puzzle() {
+        let rotations = crate::parse_rotations("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+        let count = |mode| count_by_clicks(DIAL_POSITIONS, 50, rotations.iter().copied(), mode);
+        assert_eq!(count(CountMode::EndsOnTarget), 3);
+        assert_eq!(count(CountMode::PassesTarget), 6);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_count_zero_occurrences_matches_clicks(
+            start in 0..DIAL_POSITIONS,
+            rotations in vec(rotation(1000), 0..50),
+            mode in mode(),
+        ) {
+            let expected = count_by_clicks(DIAL_POSITIONS, start, rotations.iter().copied(), mode);
+            prop_assert_eq!(count_zero_occurrences(rotations, start, mode), Ok(expected));
+        }
+
+        #[test]
+        fn prop_dial_matches_clicks_on_any_size(
+            (positions, start, rotations) in dial_and_rotations(300, 50),
+        ) {
+            for mode in CountMode::ALL {
+                let mut dial = Dial::new(positions, start);
+                let hits: i64 = rotations.iter().map(|&rotation| mode.hits(dial.rotate(rotation))).sum();
+                let expected = count_by_clicks(positions, start, rotations.iter().copied(), mode);
+                prop_assert_eq!(hits, expected, "{} positions from {} in {}", positions, start, mode);
+            }
+        }
+
+        #[test]
+        fn prop_sweep_and_segments_match_clicks_from_every_start(
+            (positions, _, rotations) in dial_and_rotations(40, 20),
+            mode in mode(),
+        ) {
+            let sweep = Sweep::new(positions, rotations.iter().copied(), mode).unwrap();
+            let segment = rotations.iter().try_fold(Segment::identity(positions), |segment, &rotation| {
+                segment.then(&Segment::rotation(positions, rotation, mode))
+            });
+            let expected: Vec<i64> = (0..positions)
+                .map(|start| count_by_clicks(positions, start, rotations.iter().copied(), mode))
+                .collect();
+            prop_assert_eq!(sweep.hits(), &expected[..]);
+            prop_assert_eq!(segment.unwrap().hits, expected);
+        }
+    }
+}
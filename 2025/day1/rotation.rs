@@ -0,0 +1,151 @@
+use aoc_common::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Which way the dial is turned: left counts down, right counts up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::new("unknown direction, expected L or R").with_text(c.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+/// A turn of the dial by a number of clicks, written e.g. "L68".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    /// Number of clicks, never negative.
+    pub steps: i64,
+}
+
+impl Rotation {
+    pub fn left(steps: i64) -> Self {
+        Self {
+            direction: Direction::Left,
+            steps,
+        }
+    }
+
+    pub fn right(steps: i64) -> Self {
+        Self {
+            direction: Direction::Right,
+            steps,
+        }
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    /// Parses a rotation such as "L68", rejecting anything around or inside it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let first = s
+            .chars()
+            .next()
+            .ok_or_else(|| ParseError::new("expected a rotation like L68"))?;
+        let (direction, rest) = s.split_at(first.len_utf8());
+        let direction = Direction::try_from(first).map_err(|_| {
+            ParseError::invalid(s, direction, "unknown direction, expected L or R")
+        })?;
+
+        if rest.is_empty() {
+            return Err(ParseError::invalid(s, s, "missing number of steps"));
+        }
+        if rest.starts_with('-') {
+            return Err(ParseError::invalid(s, rest, "number of steps cannot be negative"));
+        }
+        let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (digits, trailing) = rest.split_at(digits_end);
+        if digits.is_empty() {
+            return Err(ParseError::invalid(s, rest, "invalid number of steps"));
+        }
+        if !trailing.is_empty() {
+            return Err(ParseError::invalid(s, trailing, "unexpected characters after the number of steps"));
+        }
+        let steps = digits
+            .parse::<i64>()
+            .map_err(|err| ParseError::invalid(s, digits, format!("invalid number of steps ({err})")))?;
+
+        Ok(Self { direction, steps })
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.direction, self.steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(line: &str) -> (Option<usize>, Option<String>, String) {
+        let err = line.parse::<Rotation>().unwrap_err();
+        (err.column, err.text, err.message)
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        for line in ["L68", "R0", "R1000", "L9223372036854775807"] {
+            assert_eq!(line.parse::<Rotation>().unwrap().to_string(), line);
+        }
+        assert_eq!("L68".parse(), Ok(Rotation::left(68)));
+    }
+
+    #[test]
+    fn test_rejects_unknown_direction() {
+        let expected = (Some(1), Some("U".to_string()), "unknown direction, expected L or R".to_string());
+        assert_eq!(error("U5"), expected);
+        assert_eq!(Direction::try_from('l').unwrap_err().text.as_deref(), Some("l"));
+    }
+
+    #[test]
+    fn test_rejects_missing_and_negative_counts() {
+        assert_eq!(error("L"), (Some(1), Some("L".to_string()), "missing number of steps".to_string()));
+        assert_eq!(
+            error("R-5"),
+            (Some(2), Some("-5".to_string()), "number of steps cannot be negative".to_string())
+        );
+        assert_eq!(error("R+5").2, "invalid number of steps");
+        assert_eq!(error("R 5").1.as_deref(), Some(" 5"));
+    }
+
+    #[test]
+    fn test_rejects_trailing_garbage_and_overflow() {
+        assert_eq!(
+            error("L12abc"),
+            (
+                Some(4),
+                Some("abc".to_string()),
+                "unexpected characters after the number of steps".to_string()
+            )
+        );
+        assert_eq!(error("R5 # comment").1.as_deref(), Some(" # comment"));
+        assert_eq!("R99999999999".parse(), Ok(Rotation::right(99_999_999_999)));
+        assert_eq!(
+            error("R9223372036854775808").2,
+            "invalid number of steps (number too large to fit in target type)"
+        );
+    }
+}
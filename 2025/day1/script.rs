@@ -0,0 +1,468 @@
+//! A small language for writing rotation sequences, e.g.
+//!
+//! ```text
+//! # Wiggle back and forth across 0.
+//! let wiggle = L1, R1
+//! 3x(L20, R5); R250
+//! 1000000000x wiggle
+//! ```
+//!
+//! Statements are separated by `;` or line breaks, and the items of a statement by `,`. An item is
+//! a rotation, a macro defined earlier with `let`, a parenthesised sequence, or any of those preceded
+//! by a repeat count such as `3x`. Line breaks inside parentheses or after `,` and `=` are ignored,
+//! and `#` starts a comment running to the end of the line.
+
+use crate::{CountMode, Dial, Overflow, Rotation, Segment, DIAL_POSITIONS};
+use aoc_common::ParseError;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Rotation(Rotation),
+    Repeat { times: u64, body: Rc<[Item]> },
+}
+
+/// A parsed rotation script, which can be counted without unrolling its repeats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    items: Rc<[Item]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Number,
+    /// The `x` of a repeat count.
+    Times,
+    Word,
+    Open,
+    Close,
+    Comma,
+    Equals,
+    /// A `;` or a line break ending a statement.
+    End,
+    Eof,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: Kind,
+    text: &'a str,
+    offset: usize,
+}
+
+/// 1-based line and column of the byte at `offset` in `source`.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+/// An error about the text at `offset`, with the line and column worked out from the whole script.
+fn error_at(source: &str, offset: usize, text: &str, message: impl Into<String>) -> ParseError {
+    let (line, column) = line_and_column(source, offset);
+    ParseError::new(message).at_line(line).at_column(column).with_text(text)
+}
+
+fn lex(source: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut depth = 0_usize;
+    let mut chars = source.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        let mut end = offset + c.len_utf8();
+        let kind = match c {
+            '#' => {
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+                continue;
+            }
+            '\n' if depth > 0 => continue,
+            '\n' if tokens.last().is_some_and(|last| matches!(last.kind, Kind::Comma | Kind::Equals)) => {
+                continue;
+            }
+            '\n' | ';' => Kind::End,
+            c if c.is_whitespace() => continue,
+            '(' => {
+                depth += 1;
+                Kind::Open
+            }
+            ')' => {
+                depth = depth.saturating_sub(1);
+                Kind::Close
+            }
+            ',' => Kind::Comma,
+            '=' => Kind::Equals,
+            c if c.is_ascii_digit() => {
+                while let Some((offset, _)) = chars.next_if(|&(_, c)| c.is_ascii_digit()) {
+                    end = offset + 1;
+                }
+                tokens.push(Token {
+                    kind: Kind::Number,
+                    text: &source[offset..end],
+                    offset,
+                });
+                if let Some((offset, _)) = chars.next_if(|&(_, c)| c == 'x') {
+                    tokens.push(Token {
+                        kind: Kind::Times,
+                        text: "x",
+                        offset,
+                    });
+                }
+                continue;
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                while let Some((offset, c)) = chars.next_if(|&(_, c)| c.is_alphanumeric() || c == '_') {
+                    end = offset + c.len_utf8();
+                }
+                Kind::Word
+            }
+            _ => return Err(error_at(source, offset, &source[offset..end], "unexpected character")),
+        };
+        tokens.push(Token {
+            kind,
+            text: &source[offset..end],
+            offset,
+        });
+    }
+    tokens.push(Token {
+        kind: Kind::Eof,
+        text: "",
+        offset: source.len(),
+    });
+    Ok(tokens)
+}
+
+/// Whether a word is a rotation such as "L20" rather than the name of a macro.
+fn is_rotation(word: &str) -> bool {
+    let mut chars = word.chars();
+    matches!(chars.next(), Some('L' | 'R')) && chars.next().is_some_and(|c| c.is_ascii_digit())
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<Token<'a>>,
+    position: usize,
+    macros: HashMap<&'a str, Rc<[Item]>>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Token<'a> {
+        self.tokens[self.position]
+    }
+
+    fn advance(&mut self) -> Token<'a> {
+        let token = self.peek();
+        if token.kind != Kind::Eof {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn error(&self, token: Token, message: impl Into<String>) -> ParseError {
+        let text = if token.kind == Kind::Eof { "end of script" } else { token.text };
+        error_at(self.source, token.offset, text, message)
+    }
+
+    fn expect(&mut self, kind: Kind, message: &str) -> Result<Token<'a>, ParseError> {
+        let token = self.peek();
+        if token.kind != kind {
+            return Err(self.error(token, message));
+        }
+        Ok(self.advance())
+    }
+
+    fn expect_end(&mut self) -> Result<(), ParseError> {
+        match self.peek().kind {
+            Kind::Eof => Ok(()),
+            Kind::End => {
+                self.advance();
+                Ok(())
+            }
+            _ => Err(self.error(self.peek(), "expected `,`, `;` or a new line")),
+        }
+    }
+
+    fn script(&mut self) -> Result<Vec<Item>, ParseError> {
+        let mut items = Vec::new();
+        loop {
+            let token = self.peek();
+            match token.kind {
+                Kind::Eof => return Ok(items),
+                Kind::End => {
+                    self.advance();
+                }
+                Kind::Word if token.text == "let" => self.definition()?,
+                _ => {
+                    items.extend(self.sequence()?);
+                    self.expect_end()?;
+                }
+            }
+        }
+    }
+
+    fn definition(&mut self) -> Result<(), ParseError> {
+        self.advance();
+        let name = self.expect(Kind::Word, "expected the name of a macro")?;
+        if is_rotation(name.text) || name.text == "let" {
+            return Err(self.error(name, "macro names cannot be rotations or `let`"));
+        }
+        if self.macros.contains_key(name.text) {
+            return Err(self.error(name, "macro is already defined"));
+        }
+        self.expect(Kind::Equals, "expected `=` after the name of a macro")?;
+        let body = self.sequence()?;
+        self.expect_end()?;
+        self.macros.insert(name.text, body.into());
+        Ok(())
+    }
+
+    fn sequence(&mut self) -> Result<Vec<Item>, ParseError> {
+        let mut items = vec![self.item()?];
+        while self.peek().kind == Kind::Comma {
+            self.advance();
+            items.push(self.item()?);
+        }
+        Ok(items)
+    }
+
+    fn item(&mut self) -> Result<Item, ParseError> {
+        if self.peek().kind != Kind::Number {
+            return self.group();
+        }
+        let count = self.advance();
+        let times = count
+            .text
+            .parse()
+            .map_err(|err| self.error(count, format!("invalid repeat count ({err})")))?;
+        self.expect(Kind::Times, "expected `x` after a repeat count")?;
+        let body = match self.group()? {
+            Item::Repeat { times: 1, body } => body,
+            rotation => Rc::from([rotation]),
+        };
+        Ok(Item::Repeat { times, body })
+    }
+
+    fn group(&mut self) -> Result<Item, ParseError> {
+        let token = self.advance();
+        match token.kind {
+            Kind::Open => {
+                let body = self.sequence()?;
+                self.expect(Kind::Close, "expected `)`")?;
+                Ok(Item::Repeat {
+                    times: 1,
+                    body: body.into(),
+                })
+            }
+            Kind::Word if is_rotation(token.text) => {
+                let (line, column) = line_and_column(self.source, token.offset);
+                token
+                    .text
+                    .parse()
+                    .map(Item::Rotation)
+                    .map_err(|err: ParseError| err.shift_columns(column - 1).at_line(line))
+            }
+            Kind::Word => match self.macros.get(token.text) {
+                Some(body) => Ok(Item::Repeat {
+                    times: 1,
+                    body: body.clone(),
+                }),
+                None => Err(self.error(token, "undefined macro")),
+            },
+            _ => Err(self.error(token, "expected a rotation, a macro or `(`")),
+        }
+    }
+}
+
+impl FromStr for Script {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            source: s,
+            tokens: lex(s)?,
+            position: 0,
+            macros: HashMap::new(),
+        };
+        Ok(Self {
+            items: parser.script()?.into(),
+        })
+    }
+}
+
+/// The rotations of a script, unrolled one at a time as they are asked for.
+pub struct ScriptRotations {
+    /// The sequences being unrolled, innermost last, with the next item of each and how many more
+    /// times to go through it.
+    stack: Vec<(Rc<[Item]>, usize, u64)>,
+}
+
+impl Iterator for ScriptRotations {
+    type Item = Rotation;
+
+    fn next(&mut self) -> Option<Rotation> {
+        loop {
+            let (body, index, remaining) = self.stack.last_mut()?;
+            let Some(item) = body.get(*index).cloned() else {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    self.stack.pop();
+                } else {
+                    *index = 0;
+                }
+                continue;
+            };
+            *index += 1;
+            match item {
+                Item::Rotation(rotation) => return Some(rotation),
+                Item::Repeat { times, body } if times > 0 && !body.is_empty() => {
+                    self.stack.push((body, 0, times));
+                }
+                Item::Repeat { .. } => {}
+            }
+        }
+    }
+}
+
+/// The effect of `items`, working out each shared macro body only once.
+fn segment_of(
+    items: &Rc<[Item]>,
+    positions: i64,
+    mode: CountMode,
+    memo: &mut HashMap<*const Item, Segment>,
+) -> Result<Segment, Overflow> {
+    let key = Rc::as_ptr(items) as *const Item;
+    if let Some(segment) = memo.get(&key) {
+        return Ok(segment.clone());
+    }
+    let mut segment = Segment::identity(positions);
+    for item in items.iter() {
+        let next = match item {
+            Item::Rotation(rotation) => Segment::rotation(positions, *rotation, mode),
+            Item::Repeat { times, body } => segment_of(body, positions, mode, memo)?.repeat(*times)?,
+        };
+        segment = segment.then(&next)?;
+    }
+    memo.insert(key, segment.clone());
+    Ok(segment)
+}
+
+impl Script {
+    /// The script's rotations in order, with every repeat unrolled.
+    pub fn rotations(&self) -> ScriptRotations {
+        ScriptRotations {
+            stack: vec![(self.items.clone(), 0, 1)],
+        }
+    }
+
+    /// The combined effect of the whole script on a dial of `positions` positions.
+    ///
+    /// Each repeat is worked out from its body by repeated squaring, so this takes time in proportion
+    /// to the length of the script and the logarithm of its repeat counts rather than to the number of
+    /// rotations it stands for.
+    pub fn segment(&self, positions: i64, mode: CountMode) -> Result<Segment, Overflow> {
+        segment_of(&self.items, positions, mode, &mut HashMap::new())
+    }
+
+    /// Counts the number of times position 0 of the safe's dial is reached, as
+    /// [`crate::count_zero_occurrences`] would given [`Script::rotations`].
+    ///
+    /// # Panics
+    ///
+    /// If `initial_position` is not on the dial.
+    pub fn count(&self, initial_position: i64, mode: CountMode) -> Result<i64, Overflow> {
+        let start = Dial::new(DIAL_POSITIONS, initial_position).position();
+        Ok(self.segment(DIAL_POSITIONS, mode)?.hits[start as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::count_zero_occurrences;
+
+    fn rotations(script: &str) -> Vec<Rotation> {
+        script.parse::<Script>().unwrap().rotations().collect()
+    }
+
+    fn error(script: &str) -> (Option<usize>, Option<usize>, Option<String>, String) {
+        let err = script.parse::<Script>().unwrap_err();
+        (err.line, err.column, err.text, err.message)
+    }
+
+    #[test]
+    fn test_repeats_unroll_in_order() {
+        let expected = [
+            Rotation::left(20),
+            Rotation::right(5),
+            Rotation::left(20),
+            Rotation::right(5),
+            Rotation::left(20),
+            Rotation::right(5),
+            Rotation::right(250),
+        ];
+        assert_eq!(rotations("3x(L20, R5); R250"), expected);
+        assert_eq!(rotations("3x(L20,\n  R5)\nR250\n"), expected);
+        assert_eq!(rotations("2x(2x L1, 0x R9), R3"), rotations("L1, L1, L1, L1, R3"));
+        assert_eq!(rotations(""), []);
+    }
+
+    #[test]
+    fn test_macros_and_comments() {
+        let script = "# warm up\nlet wiggle = L1, R1  # there and back\nlet twice = 2x wiggle\n\n\
+                      twice; R5, wiggle";
+        assert_eq!(rotations(script), rotations("L1, R1, L1, R1, R5, L1, R1"));
+    }
+
+    #[test]
+    fn test_count_matches_unrolled_rotations() {
+        let scripts = [
+            "3x(L20, R5); R250",
+            "let a = L68, L30, R48\n5x(a, 2x(L5, R60)), 7x a, L82",
+            "R50, 4x(L100, 3x R1, L3)",
+        ];
+        for script in scripts {
+            let parsed: Script = script.parse().unwrap();
+            for mode in CountMode::ALL {
+                for start in [0, 50, 99] {
+                    let unrolled = count_zero_occurrences(parsed.rotations(), start, mode);
+                    assert_eq!(parsed.count(start, mode), unrolled, "{script} from {start} in {mode}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_huge_repeat_counts_are_counted_arithmetically() {
+        let script: Script = "let wiggle = L1, R1\n1000000000000x(R1)\n500000000000x wiggle".parse().unwrap();
+        // From 50 the first repeat passes 0 every 100 clicks, then the wiggles cross it from 50 never.
+        assert_eq!(script.count(50, CountMode::PassesTarget), Ok(10_000_000_000));
+        assert_eq!(script.count(50, CountMode::EndsOnTarget), Ok(10_000_000_000));
+        let nested: Script = "let a = 1000000x L7\nlet b = 1000000x a\n1000000x b".parse().unwrap();
+        assert_eq!(nested.count(0, CountMode::PassesTarget), Ok(70_000_000_000_000_000));
+        let overflowing: Script = "18446744073709551615x(R9223372036854775807)".parse().unwrap();
+        assert_eq!(overflowing.count(0, CountMode::PassesTarget), Err(Overflow));
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_problem() {
+        let undefined = (Some(2), Some(4), Some("wiggle".to_string()), "undefined macro".to_string());
+        assert_eq!(error("R5\n3x wiggle"), undefined);
+        assert_eq!(error("3x(L20, R5").3, "expected `)`");
+        assert_eq!(error("3x(L20, R5").2.as_deref(), Some("end of script"));
+        assert_eq!(error("3(L20)").3, "expected `x` after a repeat count");
+        assert_eq!(
+            error("L5\n  2x(R5x)"),
+            (
+                Some(2),
+                Some(8),
+                Some("x".to_string()),
+                "unexpected characters after the number of steps".to_string()
+            )
+        );
+        assert_eq!(error("let a = L1\nlet a = R1").3, "macro is already defined");
+        assert_eq!(error("let L1 = R1").3, "macro names cannot be rotations or `let`");
+        assert_eq!(error("L1 R1").3, "expected `,`, `;` or a new line");
+        assert_eq!(error("L1, @").1, Some(5));
+        assert!(error("99999999999999999999x L1").3.starts_with("invalid repeat count"));
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{add_hits, CountMode, Dial, Direction, Overflow, Rotation};
+use std::ops::Range;
+
+/// The combined effect of a run of rotations on a dial, whatever position it starts from.
+///
+/// Segments form a monoid under [`Segment::then`], with [`Segment::identity`] as the empty run, so
+/// the effect of any run can be built up from the effects of its halves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    /// Clicks the run moves the dial by, counting right and modulo the number of positions.
+    pub offset: i64,
+    /// Number of hits on 0 when starting from each position.
+    pub hits: Vec<i64>,
+}
+
+impl Segment {
+    /// No rotations at all on a dial of `positions` positions.
+    pub fn identity(positions: i64) -> Self {
+        Self {
+            offset: 0,
+            hits: vec![0; positions as usize],
+        }
+    }
+
+    /// A single rotation, with hits counted in `mode`.
+    pub fn rotation(positions: i64, rotation: Rotation, mode: CountMode) -> Self {
+        let offset = match rotation.direction {
+            Direction::Left => -rotation.steps,
+            Direction::Right => rotation.steps,
+        }
+        .rem_euclid(positions);
+        let hits = (0..positions)
+            .map(|start| mode.hits(Dial::new(positions, start).rotate(rotation)))
+            .collect();
+        Self { offset, hits }
+    }
+
+    pub fn positions(&self) -> i64 {
+        self.hits.len() as i64
+    }
+
+    /// This run followed by `next`, or [`Overflow`] if the hits from any position do not fit in an `i64`.
+    pub fn then(&self, next: &Segment) -> Result<Segment, Overflow> {
+        let n = self.positions();
+        let hits = (0..n)
+            .map(|start| add_hits(self.hits[start as usize], next.hits[((start + self.offset) % n) as usize]))
+            .collect::<Result<_, _>>()?;
+        Ok(Segment {
+            // Both offsets are below n, so this cannot overflow unless n is over half of i64::MAX.
+            offset: (self.offset + next.offset) % n,
+            hits,
+        })
+    }
+
+    /// This run `times` times over, built by repeated squaring rather than one run at a time.
+    pub fn repeat(&self, mut times: u64) -> Result<Segment, Overflow> {
+        let mut result = Segment::identity(self.positions());
+        let mut power = self.clone();
+        while times > 0 {
+            if times & 1 == 1 {
+                result = result.then(&power)?;
+            }
+            times >>= 1;
+            // Only square when still needed, so no power has more hits than the result.
+            if times > 0 {
+                power = power.then(&power)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// A segment tree over a list of rotations, answering how often a sub-range of them reaches 0 in
+/// logarithmic time and allowing single rotations to be replaced.
+///
+/// Every range of rotations reaches 0 no more often from any position than all of them do from some
+/// position, so once the tree is built no query can overflow.
+pub struct RotationTree {
+    positions: i64,
+    mode: CountMode,
+    rotations: Vec<Rotation>,
+    /// Node 1 is the root and node `i` has children `2i` and `2i + 1`.
+    nodes: Vec<Segment>,
+}
+
+impl RotationTree {
+    /// Builds the tree, or fails with [`Overflow`] if the rotations reach 0 too often to count.
+    ///
+    /// # Panics
+    ///
+    /// If `positions` is not positive.
+    pub fn new(positions: i64, rotations: Vec<Rotation>, mode: CountMode) -> Result<Self, Overflow> {
+        assert!(
+            positions > 0,
+            "a dial needs at least one position, got {positions}"
+        );
+        let nodes = vec![Segment::identity(positions); 4 * rotations.len().max(1)];
+        let mut tree = Self {
+            positions,
+            mode,
+            rotations,
+            nodes,
+        };
+        if !tree.rotations.is_empty() {
+            tree.build(1, 0..tree.rotations.len())?;
+        }
+        Ok(tree)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rotations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rotations.is_empty()
+    }
+
+    pub fn rotations(&self) -> &[Rotation] {
+        &self.rotations
+    }
+
+    /// Replaces the rotation at `index`, leaving the tree as it was and failing with [`Overflow`] if the
+    /// rotations would then reach 0 too often to count.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, rotation: Rotation) -> Result<(), Overflow> {
+        assert!(
+            index < self.len(),
+            "rotation {index} is out of bounds for {} rotations",
+            self.len()
+        );
+        let previous = std::mem::replace(&mut self.rotations[index], rotation);
+        self.update(1, 0..self.len(), index).inspect_err(|_| {
+            self.rotations[index] = previous;
+            self.update(1, 0..self.len(), index)
+                .expect("the tree was built with the previous rotation");
+        })
+    }
+
+    /// Number of hits on 0 by the rotations in `range`, starting from `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or `start` is not on the dial.
+    pub fn hits(&self, range: Range<usize>, start: i64) -> i64 {
+        self.query(range, start).0
+    }
+
+    /// Position the dial is left at by the rotations in `range`, starting from `start`.
+    ///
+    /// # Panics
+    ///
+    /// If `range` is out of bounds or `start` is not on the dial.
+    pub fn position_after(&self, range: Range<usize>, start: i64) -> i64 {
+        self.query(range, start).1
+    }
+
+    /// The combined effect of the rotations in `range`.
+    pub fn segment(&self, range: Range<usize>) -> Segment {
+        self.check_range(&range);
+        let mut segment = Segment::identity(self.positions);
+        self.collect(1, 0..self.len(), &range, &mut |node| {
+            segment = segment.then(node).expect("no range has more hits than the whole tree");
+        });
+        segment
+    }
+
+    fn check_range(&self, range: &Range<usize>) {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "rotations {range:?} are out of bounds for {} rotations",
+            self.len()
+        );
+    }
+
+    /// Walks the nodes covering `range` from left to right, following the dial from `start`.
+    fn query(&self, range: Range<usize>, start: i64) -> (i64, i64) {
+        self.check_range(&range);
+        assert!(
+            (0..self.positions).contains(&start),
+            "start position {start} is not on a dial of {} positions",
+            self.positions
+        );
+        let (mut hits, mut position) = (0, start);
+        self.collect(1, 0..self.len(), &range, &mut |node| {
+            hits += node.hits[position as usize];
+            position = (position + node.offset) % self.positions;
+        });
+        (hits, position)
+    }
+
+    fn build(&mut self, node: usize, span: Range<usize>) -> Result<(), Overflow> {
+        if span.len() == 1 {
+            self.nodes[node] = Segment::rotation(self.positions, self.rotations[span.start], self.mode);
+            return Ok(());
+        }
+        let mid = span.start + span.len() / 2;
+        self.build(2 * node, span.start..mid)?;
+        self.build(2 * node + 1, mid..span.end)?;
+        self.nodes[node] = self.nodes[2 * node].then(&self.nodes[2 * node + 1])?;
+        Ok(())
+    }
+
+    fn update(&mut self, node: usize, span: Range<usize>, index: usize) -> Result<(), Overflow> {
+        if span.len() == 1 {
+            self.nodes[node] = Segment::rotation(self.positions, self.rotations[index], self.mode);
+            return Ok(());
+        }
+        let mid = span.start + span.len() / 2;
+        if index < mid {
+            self.update(2 * node, span.start..mid, index)?;
+        } else {
+            self.update(2 * node + 1, mid..span.end, index)?;
+        }
+        self.nodes[node] = self.nodes[2 * node].then(&self.nodes[2 * node + 1])?;
+        Ok(())
+    }
+
+    fn collect<'a>(
+        &'a self,
+        node: usize,
+        span: Range<usize>,
+        range: &Range<usize>,
+        visit: &mut impl FnMut(&'a Segment),
+    ) {
+        if range.end <= span.start || span.end <= range.start || span.is_empty() {
+            return;
+        }
+        if range.start <= span.start && span.end <= range.end {
+            visit(&self.nodes[node]);
+            return;
+        }
+        let mid = span.start + span.len() / 2;
+        self.collect(2 * node, span.start..mid, range, visit);
+        self.collect(2 * node + 1, mid..span.end, range, visit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, parse_rotations, DIAL_POSITIONS};
+
+    /// Hits and final position of the rotations, turning the dial one rotation at a time.
+    fn scan(positions: i64, rotations: &[Rotation], start: i64, mode: CountMode) -> (i64, i64) {
+        let mut dial = Dial::new(positions, start);
+        let hits = rotations
+            .iter()
+            .map(|&rotation| mode.hits(dial.rotate(rotation)))
+            .sum();
+        (hits, dial.position())
+    }
+
+    fn example() -> Vec<Rotation> {
+        parse_rotations("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap()
+    }
+
+    #[test]
+    fn test_segment_then_is_associative_with_identity() {
+        let [a, b, c] = [Rotation::left(68), Rotation::right(148), Rotation::left(5)]
+            .map(|rotation| Segment::rotation(10, rotation, CountMode::PassesTarget));
+        let ab = a.then(&b).unwrap();
+        assert_eq!(ab.then(&c), a.then(&b.then(&c).unwrap()));
+        assert_eq!(Segment::identity(10).then(&a), Ok(a.clone()));
+        assert_eq!(a.then(&Segment::identity(10)), Ok(a));
+        assert_eq!(ab.offset, 0);
+    }
+
+    #[test]
+    fn test_repeat_matches_repeated_then() {
+        let segment = Segment::rotation(10, Rotation::right(13), CountMode::PassesTarget);
+        let mut expected = Segment::identity(10);
+        for times in 0..12 {
+            assert_eq!(segment.repeat(times), Ok(expected.clone()), "{times} times");
+            expected = expected.then(&segment).unwrap();
+        }
+        let huge = segment.repeat(1 << 60).unwrap();
+        // 13 * 2^60 clicks from 0 pass it a tenth as many times and end on 8.
+        assert_eq!((huge.offset, huge.hits[0]), (8, (13 * (1_i128 << 60) / 10) as i64));
+        assert_eq!(segment.repeat(u64::MAX), Err(Overflow));
+    }
+
+    #[test]
+    fn test_every_range_and_start_matches_scan() {
+        let rotations = example();
+        for mode in CountMode::ALL {
+            let tree = RotationTree::new(DIAL_POSITIONS, rotations.clone(), mode).unwrap();
+            for i in 0..=rotations.len() {
+                for j in i..=rotations.len() {
+                    for start in [0, 1, 32, 50, 99] {
+                        let query = (tree.hits(i..j, start), tree.position_after(i..j, start));
+                        assert_eq!(
+                            query,
+                            scan(DIAL_POSITIONS, &rotations[i..j], start, mode),
+                            "{i}..{j} from {start}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_replaces_a_rotation() {
+        let mut tree = RotationTree::new(DIAL_POSITIONS, example(), CountMode::PassesTarget).unwrap();
+        assert_eq!(tree.hits(0..tree.len(), 50), 6);
+        tree.set(2, Rotation::right(1048)).unwrap();
+        let mut edited = example();
+        edited[2] = Rotation::right(1048);
+        assert_eq!(tree.rotations(), edited);
+        assert_eq!(
+            Ok(tree.hits(0..tree.len(), 50)),
+            count_zero_occurrences(edited, 50, CountMode::PassesTarget)
+        );
+        assert_eq!(tree.segment(0..3).hits[50], 12);
+    }
+
+    #[test]
+    fn test_empty_tree_has_no_hits() {
+        let tree = RotationTree::new(DIAL_POSITIONS, Vec::new(), CountMode::EndsOnTarget).unwrap();
+        assert!(tree.is_empty());
+        assert_eq!((tree.hits(0..0, 7), tree.position_after(0..0, 7)), (0, 7));
+    }
+
+    #[test]
+    fn test_overflowing_set_leaves_tree_unchanged() {
+        let rotations = vec![Rotation::right(i64::MAX / 2); 2];
+        let mut tree = RotationTree::new(1, rotations.clone(), CountMode::PassesTarget).unwrap();
+        assert_eq!(tree.hits(0..2, 0), i64::MAX - 1);
+        assert_eq!(tree.set(1, Rotation::right(i64::MAX / 2 + 2)), Err(Overflow));
+        assert_eq!(tree.rotations(), rotations);
+        assert_eq!(tree.hits(1..2, 0), i64::MAX / 2);
+        let too_many = vec![Rotation::left(i64::MAX); 2];
+        assert!(RotationTree::new(1, too_many, CountMode::PassesTarget).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_range_past_the_end_panics() {
+        let tree = RotationTree::new(DIAL_POSITIONS, example(), CountMode::EndsOnTarget).unwrap();
+        tree.hits(3..11, 0);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{add_hits, CountMode, Dial, Overflow, Rotation, DIAL_POSITIONS};
+use aoc_common::input::parse_line_with;
+use aoc_common::ParseError;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Why rotations could not be streamed and counted.
+#[derive(Debug)]
+pub enum StreamError {
+    Read(io::Error),
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Read(err) => write!(f, "{err}"),
+            StreamError::Parse(err) => write!(f, "{err}"),
+            StreamError::Overflow(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Read(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+            StreamError::Overflow(err) => Some(err),
+        }
+    }
+}
+
+/// Rotations read from `reader` a line at a time, so only the current line is ever held in memory.
+///
+/// Blank lines are skipped and errors carry the line they were found on, as with
+/// [`crate::parse_rotations`].
+pub struct RotationReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    bytes_read: u64,
+}
+
+impl<R: BufRead> RotationReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            bytes_read: 0,
+        }
+    }
+
+    /// Number of bytes read so far.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+}
+
+impl<R: BufRead> Iterator for RotationReader<R> {
+    type Item = Result<Rotation, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(read) => self.bytes_read += read as u64,
+                Err(err) => return Some(Err(StreamError::Read(err))),
+            }
+            self.line_number += 1;
+            if let Some(result) = parse_line_with(self.line_number, &self.line, str::parse) {
+                return Some(result.map_err(StreamError::Parse));
+            }
+        }
+    }
+}
+
+/// What counting a stream of rotations found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamCounts {
+    pub rotations: u64,
+    pub bytes: u64,
+    /// Hits on 0 in each of [`CountMode::ALL`].
+    hits: [i64; 2],
+}
+
+impl StreamCounts {
+    pub fn hits(&self, mode: CountMode) -> i64 {
+        match mode {
+            CountMode::EndsOnTarget => self.hits[0],
+            CountMode::PassesTarget => self.hits[1],
+        }
+    }
+}
+
+/// Counts hits on 0 in every mode over rotations read from `reader`, in a single pass and in constant
+/// memory however long the input is.
+///
+/// # Returns
+///
+/// The counts, or the first error reading, parsing or counting the rotations.
+pub fn count_streamed<R: BufRead>(reader: R, initial_position: i64) -> Result<StreamCounts, StreamError> {
+    let mut dial = Dial::new(DIAL_POSITIONS, initial_position);
+    let mut rotations = RotationReader::new(reader);
+    let mut counts = StreamCounts {
+        rotations: 0,
+        bytes: 0,
+        hits: [0; 2],
+    };
+    for rotation in rotations.by_ref() {
+        let turn = dial.rotate(rotation?);
+        for (hits, mode) in counts.hits.iter_mut().zip(CountMode::ALL) {
+            *hits = add_hits(*hits, mode.hits(turn)).map_err(StreamError::Overflow)?;
+        }
+        counts.rotations += 1;
+    }
+    counts.bytes = rotations.bytes_read();
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, parse_rotations, START_POSITION};
+    use std::io::{Cursor, Read};
+
+    /// `count` copies of `pattern` one after the other, produced as they are read.
+    struct Repeated {
+        pattern: &'static [u8],
+        count: usize,
+        offset: usize,
+    }
+
+    impl Read for Repeated {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let mut written = 0;
+            while written < buf.len() && self.count > 0 {
+                let chunk = &self.pattern[self.offset..];
+                let length = chunk.len().min(buf.len() - written);
+                buf[written..written + length].copy_from_slice(&chunk[..length]);
+                written += length;
+                self.offset += length;
+                if self.offset == self.pattern.len() {
+                    self.offset = 0;
+                    self.count -= 1;
+                }
+            }
+            Ok(written)
+        }
+    }
+
+    #[test]
+    fn test_reader_matches_parse_rotations() {
+        let raw = "L68\n\n  L30\r\nR48\nL5";
+        let streamed: Vec<Rotation> = RotationReader::new(Cursor::new(raw)).map(Result::unwrap).collect();
+        assert_eq!(streamed, parse_rotations(raw).unwrap());
+    }
+
+    #[test]
+    fn test_reader_reports_line_of_bad_rotation() {
+        let mut rotations = RotationReader::new(Cursor::new("L68\n\n  R5x\nL1\n"));
+        assert_eq!(rotations.next().unwrap().unwrap(), Rotation::left(68));
+        let Some(Err(StreamError::Parse(err))) = rotations.next() else {
+            panic!("the third line should not parse");
+        };
+        assert_eq!(err.to_string(), "3:5: unexpected characters after the number of steps: \"x\"");
+        assert_eq!(rotations.bytes_read(), 11);
+    }
+
+    #[test]
+    fn test_streamed_counts_match_parsed_input() {
+        let raw = "L68\nL30\nR48\nL5\nR60\n\nL55\nL1\nL99\nR14\nL82\nR1000\nL250\n";
+        let counts = count_streamed(Cursor::new(raw), START_POSITION).unwrap();
+        let rotations = parse_rotations(raw).unwrap();
+        assert_eq!(counts.rotations, rotations.len() as u64);
+        assert_eq!(counts.bytes, raw.len() as u64);
+        for mode in CountMode::ALL {
+            let count = count_zero_occurrences(rotations.iter().copied(), START_POSITION, mode);
+            assert_eq!(Ok(counts.hits(mode)), count);
+        }
+    }
+
+    #[test]
+    fn test_counts_a_long_stream() {
+        // A million rotations, produced as they are read rather than held as text.
+        let reader = Repeated {
+            pattern: b"R1000000050\nL3000000050\n",
+            count: 500_000,
+            offset: 0,
+        };
+        let counts = count_streamed(io::BufReader::new(reader), 50).unwrap();
+        assert_eq!((counts.rotations, counts.bytes), (1_000_000, 24 * 500_000));
+        // Every pair goes 50 -> 0 -> 50.
+        assert_eq!(counts.hits(CountMode::EndsOnTarget), 500_000);
+        assert_eq!(counts.hits(CountMode::PassesTarget), 500_000 * (10_000_001 + 30_000_000));
+    }
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let reader = Repeated {
+            pattern: b"R9223372036854775807\n",
+            count: 101,
+            offset: 0,
+        };
+        let err = count_streamed(io::BufReader::new(reader), 0).unwrap_err();
+        assert!(matches!(err, StreamError::Overflow(Overflow)), "{err}");
+    }
+}
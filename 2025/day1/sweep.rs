@@ -0,0 +1,175 @@
+use crate::{add_hits, CountMode, Direction, Overflow, Rotation};
+
+/// Hits on 0 for every starting position of a dial at once.
+///
+/// A rotation reaches 0 some fixed number of times from any position, plus once more from a run of
+/// neighbouring positions. Following where each starting position has got to only shifts that run, so
+/// every rotation adds a constant and a range to a difference array and the whole sweep takes one pass
+/// over the rotations plus one over the positions, rather than a simulation per starting position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sweep {
+    hits: Vec<i64>,
+}
+
+/// Positions a rotation reaches 0 from one more time than `base`, as an inclusive range.
+struct Extra {
+    base: i64,
+    from: Option<(i64, i64)>,
+}
+
+fn extra(positions: i64, rotation: Rotation, mode: CountMode) -> Extra {
+    let n = positions;
+    let (laps, remainder) = (rotation.steps / n, rotation.steps % n);
+    match (mode, rotation.direction) {
+        (CountMode::EndsOnTarget, Direction::Right) => Extra {
+            base: 0,
+            from: Some(((n - remainder) % n, (n - remainder) % n)),
+        },
+        (CountMode::EndsOnTarget, Direction::Left) => Extra {
+            base: 0,
+            from: Some((remainder, remainder)),
+        },
+        (CountMode::PassesTarget, _) if remainder == 0 => Extra { base: laps, from: None },
+        (CountMode::PassesTarget, Direction::Right) => Extra {
+            base: laps,
+            from: Some((n - remainder, n - 1)),
+        },
+        (CountMode::PassesTarget, Direction::Left) => Extra {
+            base: laps,
+            from: Some((1, remainder)),
+        },
+    }
+}
+
+impl Sweep {
+    /// Sweeps a dial of `positions` positions through `rotations`, counting hits in `mode`, or fails with
+    /// [`Overflow`] if the hits from any position do not fit in an `i64`.
+    ///
+    /// # Panics
+    ///
+    /// If `positions` is not positive.
+    pub fn new(
+        positions: i64,
+        rotations: impl IntoIterator<Item = Rotation>,
+        mode: CountMode,
+    ) -> Result<Self, Overflow> {
+        assert!(positions > 0, "a dial needs at least one position, got {positions}");
+        let n = positions;
+        let mut base = 0;
+        // How far every starting position has moved, and the changes in hits between neighbouring starts.
+        let mut offset = 0;
+        let mut changes = vec![0; n as usize + 1];
+        for rotation in rotations {
+            let Extra { base: laps, from } = extra(n, rotation, mode);
+            base = add_hits(base, laps)?;
+            if let Some((low, high)) = from {
+                let start = (low - offset).rem_euclid(n) as usize;
+                let end = start + (high - low + 1) as usize;
+                changes[start] += 1;
+                if end <= n as usize {
+                    changes[end] -= 1;
+                } else {
+                    changes[n as usize] -= 1;
+                    changes[0] += 1;
+                    changes[end - n as usize] -= 1;
+                }
+            }
+            offset = match rotation.direction {
+                Direction::Left => offset - rotation.steps % n,
+                Direction::Right => offset + rotation.steps % n,
+            }
+            .rem_euclid(n);
+        }
+        let hits = changes[..n as usize]
+            .iter()
+            .scan(Ok(base), |hits, &change| {
+                *hits = hits.and_then(|hits| add_hits(hits, change));
+                Some(*hits)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { hits })
+    }
+
+    /// Number of hits from each starting position, indexed by position.
+    pub fn hits(&self) -> &[i64] {
+        &self.hits
+    }
+
+    /// The starting position with the most hits and its hits, the lowest such position on a tie.
+    pub fn best(&self) -> (i64, i64) {
+        // max_by_key keeps the last of equal elements.
+        self.enumerate().rev().max_by_key(|&(_, hits)| hits).unwrap()
+    }
+
+    /// The starting position with the fewest hits and its hits, the lowest such position on a tie.
+    pub fn worst(&self) -> (i64, i64) {
+        self.enumerate().min_by_key(|&(_, hits)| hits).unwrap()
+    }
+
+    fn enumerate(&self) -> impl DoubleEndedIterator<Item = (i64, i64)> + '_ {
+        self.hits.iter().enumerate().map(|(start, &hits)| (start as i64, hits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, parse_rotations, Dial, Segment, DIAL_POSITIONS};
+
+    /// Hits from every starting position, simulating each one separately.
+    fn simulate(positions: i64, rotations: &[Rotation], mode: CountMode) -> Vec<i64> {
+        (0..positions)
+            .map(|start| {
+                let mut dial = Dial::new(positions, start);
+                rotations.iter().map(|&rotation| mode.hits(dial.rotate(rotation))).sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sweep_matches_simulating_every_start() {
+        let rotations = parse_rotations("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\nR0\nL10\nR7").unwrap();
+        for positions in [1, 2, 7, 10, 100] {
+            for mode in CountMode::ALL {
+                let sweep = Sweep::new(positions, rotations.iter().copied(), mode).unwrap();
+                let expected = simulate(positions, &rotations, mode);
+                assert_eq!(sweep.hits(), expected, "{positions} positions, {mode}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sweep_of_example_matches_segment_fold() {
+        // The puzzle's example, then rotations of several laps.
+        let example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\nR1000\nL250";
+        let rotations = parse_rotations(example).unwrap();
+        for mode in CountMode::ALL {
+            let sweep = Sweep::new(DIAL_POSITIONS, rotations.iter().copied(), mode).unwrap();
+            let fold = rotations.iter().try_fold(Segment::identity(DIAL_POSITIONS), |segment, &rotation| {
+                segment.then(&Segment::rotation(DIAL_POSITIONS, rotation, mode))
+            });
+            assert_eq!(sweep.hits(), fold.unwrap().hits);
+            assert_eq!(Ok(sweep.hits()[50]), count_zero_occurrences(rotations.iter().copied(), 50, mode));
+        }
+    }
+
+    #[test]
+    fn test_sweep_with_billions_of_steps() {
+        let rotations = [Rotation::right(5_000_000_003), Rotation::left(i64::MAX)];
+        let sweep = Sweep::new(10, rotations, CountMode::PassesTarget).unwrap();
+        assert_eq!(sweep.hits(), simulate(10, &rotations, CountMode::PassesTarget));
+        assert_eq!(sweep.best().1, 500_000_000 + i64::MAX / 10 + 2);
+        let rotations = [Rotation::right(i64::MAX), Rotation::right(1)];
+        assert_eq!(Sweep::new(1, rotations, CountMode::PassesTarget), Err(Overflow));
+    }
+
+    #[test]
+    fn test_best_and_worst_prefer_lowest_start() {
+        // R3 reaches 0 from 7, 8 and 9, then L13 passes it once from anywhere and twice from 8, 9 and 0.
+        let rotations = [Rotation::right(3), Rotation::left(13)];
+        let sweep = Sweep::new(10, rotations, CountMode::PassesTarget).unwrap();
+        assert_eq!(sweep.hits(), [2, 1, 1, 1, 1, 1, 1, 2, 3, 3]);
+        assert_eq!(sweep.best(), (8, 3));
+        assert_eq!(sweep.worst(), (1, 1));
+    }
+}
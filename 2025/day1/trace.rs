@@ -0,0 +1,315 @@
+use crate::{add_hits, CountMode, Dial, Direction, Rotation, Turn};
+
+/// Something that happened while turning the dial, in the order it happened.
+///
+/// Rotations are numbered from 1 and so are the clicks within each rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// A rotation starts with the dial at `position`.
+    Start {
+        index: usize,
+        rotation: Rotation,
+        position: i64,
+    },
+    /// A single click moved the dial to `position`; only traced when asked for.
+    Click { index: usize, click: i64, position: i64 },
+    /// Click number `click` of the rotation left the dial at 0.
+    Zero { index: usize, click: i64 },
+    /// The rotation ended at `position`, reaching 0 `hits` times in the traced mode.
+    End {
+        index: usize,
+        position: i64,
+        hits: i64,
+        /// Running total of hits, which after the last rotation is the puzzle answer.
+        total: i64,
+    },
+}
+
+impl TraceEvent {
+    /// Header of the table printed by [`TraceEvent::table_row`].
+    pub const TABLE_HEADER: &'static str = "rotation  event  click  position   hits  total";
+
+    pub fn table_row(&self) -> String {
+        match *self {
+            TraceEvent::Start {
+                index,
+                rotation,
+                position,
+            } => format!("{index:>8}  start  {:>5}  {position:>8}", rotation.to_string()),
+            TraceEvent::Click { index, click, position } => {
+                format!("{index:>8}  click  {click:>5}  {position:>8}")
+            }
+            TraceEvent::Zero { index, click } => format!("{index:>8}  zero   {click:>5}  {:>8}", 0),
+            TraceEvent::End {
+                index,
+                position,
+                hits,
+                total,
+            } => format!("{index:>8}  end    {:>5}  {position:>8}  {hits:>5}  {total:>5}", ""),
+        }
+    }
+
+    /// The event as a single line of JSON.
+    pub fn json_line(&self) -> String {
+        match *self {
+            TraceEvent::Start {
+                index,
+                rotation,
+                position,
+            } => format!(r#"{{"event":"start","index":{index},"rotation":"{rotation}","position":{position}}}"#),
+            TraceEvent::Click { index, click, position } => {
+                format!(r#"{{"event":"click","index":{index},"click":{click},"position":{position}}}"#)
+            }
+            TraceEvent::Zero { index, click } => {
+                format!(r#"{{"event":"zero","index":{index},"click":{click},"position":0}}"#)
+            }
+            TraceEvent::End {
+                index,
+                position,
+                hits,
+                total,
+            } => format!(
+                r#"{{"event":"end","index":{index},"position":{position},"hits":{hits},"total":{total}}}"#
+            ),
+        }
+    }
+}
+
+/// The rotation being traced.
+struct Current {
+    index: usize,
+    rotation: Rotation,
+    start: i64,
+    turn: Turn,
+    /// Last click reported, 0 before the first.
+    click: i64,
+    /// Number of zero events reported so far.
+    zeros: i64,
+}
+
+/// Iterator over the [`TraceEvent`]s of turning a dial through a sequence of rotations.
+///
+/// Each rotation is applied with [`Dial::rotate`] and counted with [`CountMode::hits`], exactly as
+/// [`crate::count_zero_occurrences`] does, so the final total is always the puzzle answer.
+///
+/// # Panics
+///
+/// If the running total overflows, which [`crate::count_zero_occurrences`] reports as an error.
+pub struct Trace<I> {
+    rotations: I,
+    dial: Dial,
+    mode: CountMode,
+    clicks: bool,
+    index: usize,
+    total: i64,
+    current: Option<Current>,
+}
+
+impl<I: Iterator<Item = Rotation>> Trace<I> {
+    pub fn new(dial: Dial, rotations: impl IntoIterator<IntoIter = I>, mode: CountMode) -> Self {
+        Self {
+            rotations: rotations.into_iter(),
+            dial,
+            mode,
+            clicks: false,
+            index: 0,
+            total: 0,
+            current: None,
+        }
+    }
+
+    /// Also reports every click, not just the clicks that reach 0.
+    pub fn with_clicks(mut self) -> Self {
+        self.clicks = true;
+        self
+    }
+
+    /// Position after `click` clicks of the current rotation.
+    fn position_after(&self, current: &Current, click: i64) -> i64 {
+        let n = self.dial.positions();
+        match current.rotation.direction {
+            Direction::Right => (current.start + click % n) % n,
+            Direction::Left => (current.start - click % n).rem_euclid(n),
+        }
+    }
+
+    /// The click of the current rotation that reaches 0 for the `k`th time, counting from 0.
+    fn zero_click(&self, current: &Current, k: i64) -> i64 {
+        let n = self.dial.positions();
+        let first = match (current.start, current.rotation.direction) {
+            (0, _) => n,
+            (start, Direction::Right) => n - start,
+            (start, Direction::Left) => start,
+        };
+        first + k * n
+    }
+
+    fn next_in_rotation(&mut self) -> Option<TraceEvent> {
+        let mut current = self.current.take()?;
+        let zero_pending = current.zeros < current.turn.zero_crossings;
+        let event = if self.clicks && current.click < current.rotation.steps {
+            if zero_pending && self.zero_click(&current, current.zeros) == current.click {
+                current.zeros += 1;
+                TraceEvent::Zero {
+                    index: current.index,
+                    click: current.click,
+                }
+            } else {
+                current.click += 1;
+                TraceEvent::Click {
+                    index: current.index,
+                    click: current.click,
+                    position: self.position_after(&current, current.click),
+                }
+            }
+        } else if zero_pending {
+            let click = self.zero_click(&current, current.zeros);
+            current.zeros += 1;
+            TraceEvent::Zero {
+                index: current.index,
+                click,
+            }
+        } else {
+            let hits = self.mode.hits(current.turn);
+            self.total = add_hits(self.total, hits).expect("the total number of hits overflowed");
+            return Some(TraceEvent::End {
+                index: current.index,
+                position: current.turn.position,
+                hits,
+                total: self.total,
+            });
+        };
+        self.current = Some(current);
+        Some(event)
+    }
+}
+
+impl<I: Iterator<Item = Rotation>> Iterator for Trace<I> {
+    type Item = TraceEvent;
+
+    fn next(&mut self) -> Option<TraceEvent> {
+        if self.current.is_some() {
+            return self.next_in_rotation();
+        }
+        let rotation = self.rotations.next()?;
+        self.index += 1;
+        let start = self.dial.position();
+        let turn = self.dial.rotate(rotation);
+        self.current = Some(Current {
+            index: self.index,
+            rotation,
+            start,
+            turn,
+            click: 0,
+            zeros: 0,
+        });
+        Some(TraceEvent::Start {
+            index: self.index,
+            rotation,
+            position: start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, parse_rotations, DIAL_POSITIONS, START_POSITION};
+
+    fn trace(rotations: &[Rotation], mode: CountMode) -> Vec<TraceEvent> {
+        Trace::new(Dial::new(10, 5), rotations.iter().copied(), mode).collect()
+    }
+
+    #[test]
+    fn test_trace_reports_start_zeros_and_end() {
+        let events = trace(&[Rotation::right(17), Rotation::left(2)], CountMode::PassesTarget);
+        assert_eq!(
+            events,
+            vec![
+                TraceEvent::Start {
+                    index: 1,
+                    rotation: Rotation::right(17),
+                    position: 5
+                },
+                TraceEvent::Zero { index: 1, click: 5 },
+                TraceEvent::Zero { index: 1, click: 15 },
+                TraceEvent::End {
+                    index: 1,
+                    position: 2,
+                    hits: 2,
+                    total: 2
+                },
+                TraceEvent::Start {
+                    index: 2,
+                    rotation: Rotation::left(2),
+                    position: 2
+                },
+                TraceEvent::Zero { index: 2, click: 2 },
+                TraceEvent::End {
+                    index: 2,
+                    position: 0,
+                    hits: 1,
+                    total: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_click_trace_marks_every_click_onto_zero() {
+        let rotations = [Rotation::left(23), Rotation::right(0), Rotation::right(10)];
+        let events: Vec<TraceEvent> = Trace::new(Dial::new(10, 0), rotations, CountMode::PassesTarget)
+            .with_clicks()
+            .collect();
+        let mut last_click = None;
+        let mut zeros = 0;
+        for event in &events {
+            match *event {
+                TraceEvent::Click { position, .. } => last_click = Some(position),
+                TraceEvent::Zero { .. } => {
+                    assert_eq!(last_click, Some(0));
+                    zeros += 1;
+                }
+                _ => last_click = None,
+            }
+        }
+        let clicks = events.iter().filter(|event| matches!(event, TraceEvent::Click { .. })).count();
+        assert_eq!((clicks, zeros), (33, 3));
+    }
+
+    #[test]
+    fn test_trace_total_matches_count() {
+        // The puzzle's example, then rotations of several laps.
+        let example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\nR1000\nL250";
+        let rotations = parse_rotations(example).unwrap();
+        for mode in CountMode::ALL {
+            let dial = Dial::new(DIAL_POSITIONS, START_POSITION);
+            let last = Trace::new(dial, rotations.iter().copied(), mode).last();
+            let Some(TraceEvent::End { total, .. }) = last else {
+                panic!("trace should end with an end event, got {last:?}");
+            };
+            assert_eq!(Ok(total), count_zero_occurrences(rotations.iter().copied(), START_POSITION, mode));
+        }
+    }
+
+    #[test]
+    fn test_table_row_and_json_line() {
+        let start = TraceEvent::Start {
+            index: 3,
+            rotation: Rotation::left(68),
+            position: 50,
+        };
+        assert_eq!(start.table_row(), "       3  start    L68        50");
+        assert_eq!(
+            start.json_line(),
+            r#"{"event":"start","index":3,"rotation":"L68","position":50}"#
+        );
+        let end = TraceEvent::End {
+            index: 3,
+            position: 82,
+            hits: 1,
+            total: 4,
+        };
+        assert_eq!(end.json_line(), r#"{"event":"end","index":3,"position":82,"hits":1,"total":4}"#);
+    }
+}
@@ -0,0 +1,403 @@
+use crate::{add_hits, CountMode, Dial, Rotation};
+use std::f64::consts::TAU;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The dial at one moment of an animation: when a rotation starts and after each of the clicks shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// Rotation being made, numbered from 1.
+    pub index: usize,
+    pub rotation: Rotation,
+    /// Number of clicks of the rotation made so far.
+    pub click: i64,
+    pub position: i64,
+    /// Whether the dial reached 0 since the previous frame.
+    pub zero: bool,
+    /// Running count of hits in the animated mode.
+    pub total: i64,
+}
+
+/// `numerator / denominator` rounded up, for positive numbers, without overflowing.
+fn div_ceil(numerator: i64, denominator: i64) -> i64 {
+    numerator / denominator + i64::from(numerator % denominator != 0)
+}
+
+/// The rotation being animated.
+struct Current {
+    index: usize,
+    rotation: Rotation,
+    /// The dial before the rotation.
+    start: Dial,
+    /// Last click shown and how many times the dial had reached 0 by then.
+    click: i64,
+    zeros: i64,
+    /// Clicks between frames.
+    stride: i64,
+}
+
+/// Iterator over the [`Frame`]s of turning a dial through a sequence of rotations, one click at a time
+/// or, with [`Animation::with_max_clicks`], a few evenly spaced clicks of each long rotation.
+///
+/// The count goes up on every click onto 0 when counting passes, and at the end of a rotation that
+/// stops on 0 when counting ends, so the last frame always shows the puzzle answer.
+///
+/// # Panics
+///
+/// If the running count overflows, which [`crate::count_zero_occurrences`] reports as an error.
+pub struct Animation<I> {
+    rotations: I,
+    dial: Dial,
+    mode: CountMode,
+    max_clicks: i64,
+    index: usize,
+    total: i64,
+    current: Option<Current>,
+}
+
+impl<I: Iterator<Item = Rotation>> Animation<I> {
+    pub fn new(dial: Dial, rotations: impl IntoIterator<IntoIter = I>, mode: CountMode) -> Self {
+        Self {
+            rotations: rotations.into_iter(),
+            dial,
+            mode,
+            max_clicks: i64::MAX,
+            index: 0,
+            total: 0,
+            current: None,
+        }
+    }
+
+    /// Shows at most `max_clicks` clicks of each rotation, evenly spaced and always including the last.
+    ///
+    /// # Panics
+    ///
+    /// If `max_clicks` is not positive.
+    pub fn with_max_clicks(mut self, max_clicks: i64) -> Self {
+        assert!(max_clicks > 0, "an animation needs at least one click per rotation");
+        self.max_clicks = max_clicks;
+        self
+    }
+
+    /// Number of frames shown for `rotation`, its start and the clicks shown.
+    pub fn frames_for(&self, rotation: Rotation) -> i64 {
+        if rotation.steps == 0 {
+            return 1;
+        }
+        1 + div_ceil(rotation.steps, div_ceil(rotation.steps, self.max_clicks))
+    }
+
+    fn add(&mut self, hits: i64) {
+        self.total = add_hits(self.total, hits).expect("the total number of hits overflowed");
+    }
+
+    /// Counts the end of a rotation that has left the dial at `position`.
+    fn finish(&mut self, position: i64) {
+        self.current = None;
+        if self.mode == CountMode::EndsOnTarget && position == 0 {
+            self.add(1);
+        }
+    }
+}
+
+impl<I: Iterator<Item = Rotation>> Iterator for Animation<I> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        let Some(current) = &mut self.current else {
+            let rotation = self.rotations.next()?;
+            self.index += 1;
+            let start = self.dial;
+            self.dial.rotate(rotation);
+            self.current = Some(Current {
+                index: self.index,
+                rotation,
+                start,
+                click: 0,
+                zeros: 0,
+                stride: div_ceil(rotation.steps, self.max_clicks).max(1),
+            });
+            if rotation.steps == 0 {
+                self.finish(start.position());
+            }
+            return Some(Frame {
+                index: self.index,
+                rotation,
+                click: 0,
+                position: start.position(),
+                zero: false,
+                total: self.total,
+            });
+        };
+        let rotation = current.rotation;
+        let click = current.click.saturating_add(current.stride).min(rotation.steps);
+        let mut dial = current.start;
+        let turn = dial.rotate(Rotation { steps: click, ..rotation });
+        let reached = turn.zero_crossings - current.zeros;
+        (current.click, current.zeros) = (click, turn.zero_crossings);
+        let index = current.index;
+        if self.mode == CountMode::PassesTarget {
+            self.add(reached);
+        }
+        if click == rotation.steps {
+            self.finish(turn.position);
+        }
+        Some(Frame {
+            index,
+            rotation,
+            click,
+            position: turn.position,
+            zero: reached > 0,
+            total: self.total,
+        })
+    }
+}
+
+const TICK: char = '·';
+const ZERO: char = '0';
+const POINTER: char = '●';
+/// The pointer when it has just reached 0.
+const POINTER_AT_ZERO: char = '◉';
+
+/// Draws [`Frame`]s of a dial as a ring of text, 0 at the top and counting up clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ring {
+    positions: i64,
+    radius: usize,
+    color: bool,
+    mode: CountMode,
+}
+
+impl Ring {
+    /// A ring for a dial of `positions` positions, counting in `mode`, ten lines high either side of its
+    /// centre.
+    pub fn new(positions: i64, mode: CountMode) -> Self {
+        Self {
+            positions,
+            radius: 10,
+            color: false,
+            mode,
+        }
+    }
+
+    /// Makes the ring `radius` lines high either side of its centre, and twice that wide to look round.
+    pub fn with_radius(mut self, radius: usize) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Highlights the pointer with terminal colours, red when it has just reached 0.
+    pub fn with_color(mut self) -> Self {
+        self.color = true;
+        self
+    }
+
+    /// Line and column of `position` on the ring.
+    fn cell(&self, position: i64) -> (usize, usize) {
+        let angle = TAU * position as f64 / self.positions as f64;
+        let radius = self.radius as f64;
+        let line = radius - radius * angle.cos();
+        let column = 2.0 * radius + 2.0 * radius * angle.sin();
+        (line.round() as usize, column.round() as usize)
+    }
+
+    /// The frame as text, the ring followed by what the dial is doing and the count so far.
+    pub fn render(&self, frame: &Frame) -> String {
+        let mut grid = vec![vec![' '; 4 * self.radius + 1]; 2 * self.radius + 1];
+        for position in 0..self.positions {
+            let (line, column) = self.cell(position);
+            grid[line][column] = TICK;
+        }
+        let (line, column) = self.cell(0);
+        grid[line][column] = ZERO;
+        let label: Vec<char> = frame.position.to_string().chars().collect();
+        let start = (2 * self.radius + 1).saturating_sub(label.len() / 2 + 1);
+        for (offset, &c) in label.iter().enumerate() {
+            if let Some(cell) = grid[self.radius].get_mut(start + offset) {
+                *cell = c;
+            }
+        }
+        let (line, column) = self.cell(frame.position);
+        grid[line][column] = if frame.zero { POINTER_AT_ZERO } else { POINTER };
+
+        let mut text = String::new();
+        for (number, row) in grid.iter().enumerate() {
+            let mut row: String = row.iter().collect();
+            if self.color && number == line {
+                let color = if frame.zero { "\x1b[1;31m" } else { "\x1b[1;33m" };
+                let pointer = row.char_indices().nth(column).map_or(0, |(offset, _)| offset);
+                row.insert_str(pointer + grid[line][column].len_utf8(), "\x1b[0m");
+                row.insert_str(pointer, color);
+            }
+            text.push_str(row.trim_end());
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "rotation {} {:<6} click {}/{}  position {}\n",
+            frame.index,
+            frame.rotation.to_string(),
+            frame.click,
+            frame.rotation.steps,
+            frame.position
+        ));
+        text.push_str(&format!("count ({}): {}", self.mode, frame.total));
+        if frame.zero {
+            text.push_str("  <- reached 0");
+        }
+        text.push('\n');
+        text
+    }
+}
+
+/// Writes each frame to its own numbered text file in `dir`, e.g. "frame-000001.txt", creating `dir` if
+/// needed.
+///
+/// # Returns
+///
+/// Number of frames written.
+pub fn dump_frames(dir: &Path, ring: &Ring, frames: impl IntoIterator<Item = Frame>) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let mut written = 0;
+    for frame in frames {
+        written += 1;
+        fs::write(dir.join(format!("frame-{written:06}.txt")), ring.render(&frame))?;
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_zero_occurrences, parse_rotations, DIAL_POSITIONS, START_POSITION};
+    use aoc_common::testing::temp_dir;
+
+    fn animate(positions: i64, start: i64, rotations: &[Rotation], mode: CountMode) -> Vec<Frame> {
+        Animation::new(Dial::new(positions, start), rotations.iter().copied(), mode).collect()
+    }
+
+    #[test]
+    fn test_animation_shows_every_click_and_counts_as_it_goes() {
+        let frames = animate(8, 6, &[Rotation::right(3), Rotation::left(1)], CountMode::PassesTarget);
+        let summary: Vec<(i64, i64, bool, i64)> = frames
+            .iter()
+            .map(|frame| (frame.click, frame.position, frame.zero, frame.total))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0, 6, false, 0),
+                (1, 7, false, 0),
+                (2, 0, true, 1),
+                (3, 1, false, 1),
+                (0, 1, false, 1),
+                (1, 0, true, 2),
+            ]
+        );
+        let ends = animate(8, 6, &[Rotation::right(3), Rotation::left(1)], CountMode::EndsOnTarget);
+        let totals: Vec<i64> = ends.iter().map(|frame| frame.total).collect();
+        // Ending on 0 is counted on the click that gets there, as is a rotation of no clicks.
+        assert_eq!(totals, [0, 0, 0, 0, 0, 1]);
+        let still = animate(8, 0, &[Rotation::right(0)], CountMode::EndsOnTarget);
+        assert_eq!(still.iter().map(|frame| frame.total).collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn test_long_rotations_show_evenly_spaced_clicks() {
+        let rotations = [Rotation::right(25), Rotation::left(3), Rotation::left(i64::MAX)];
+        let dial = Dial::new(10, 0);
+        let animation = Animation::new(dial, rotations, CountMode::PassesTarget).with_max_clicks(4);
+        let frame_counts = rotations.map(|rotation| animation.frames_for(rotation));
+        assert_eq!(frame_counts, [5, 4, 5]);
+        let frames: Vec<Frame> = animation.collect();
+        assert_eq!(frames.len() as i64, frame_counts.iter().sum::<i64>());
+        let summary: Vec<(i64, i64, bool, i64)> = frames[..9]
+            .iter()
+            .map(|frame| (frame.click, frame.position, frame.zero, frame.total))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0, 0, false, 0),
+                (7, 7, false, 0),
+                (14, 4, true, 1),
+                (21, 1, true, 2),
+                (25, 5, false, 2),
+                (0, 5, false, 2),
+                (1, 4, false, 2),
+                (2, 3, false, 2),
+                (3, 2, false, 2),
+            ]
+        );
+        let last = frames.last().unwrap();
+        // From 2 the last rotation passes 0 once before its whole laps.
+        assert_eq!((last.click, last.total), (i64::MAX, 3 + i64::MAX / 10));
+    }
+
+    #[test]
+    fn test_last_frame_shows_the_answer() {
+        let rotations = parse_rotations("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+        for mode in CountMode::ALL {
+            let frames = animate(DIAL_POSITIONS, START_POSITION, &rotations, mode);
+            let clicks: i64 = rotations.iter().map(|rotation| rotation.steps).sum();
+            assert_eq!(frames.len() as i64, clicks + rotations.len() as i64);
+            let answer = count_zero_occurrences(rotations.iter().copied(), START_POSITION, mode);
+            assert_eq!(Ok(frames.last().unwrap().total), answer);
+            let dial = Dial::new(DIAL_POSITIONS, START_POSITION);
+            let sampled = Animation::new(dial, rotations.iter().copied(), mode).with_max_clicks(7).last();
+            assert_eq!(Ok(sampled.unwrap().total), answer);
+        }
+    }
+
+    #[test]
+    fn test_render_snapshot() {
+        let ring = Ring::new(8, CountMode::PassesTarget).with_radius(3);
+        let frames = animate(8, 6, &[Rotation::right(3)], CountMode::PassesTarget);
+        let expected = [
+            "      0",
+            "  ·       ·",
+            "",
+            "●     6     ·",
+            "",
+            "  ·       ·",
+            "      ·",
+            "rotation 1 R3     click 0/3  position 6",
+            "count (passes): 0",
+            "",
+        ];
+        assert_eq!(ring.render(&frames[0]), expected.join("\n"));
+        let expected = [
+            "      ◉",
+            "  ·       ·",
+            "",
+            "·     0     ·",
+            "",
+            "  ·       ·",
+            "      ·",
+            "rotation 1 R3     click 2/3  position 0",
+            "count (passes): 1  <- reached 0",
+            "",
+        ];
+        assert_eq!(ring.render(&frames[2]), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_color_wraps_only_the_pointer() {
+        let ring = Ring::new(8, CountMode::PassesTarget).with_radius(3).with_color();
+        let frames = animate(8, 6, &[Rotation::right(3)], CountMode::PassesTarget);
+        let text = ring.render(&frames[2]);
+        assert!(text.starts_with("      \x1b[1;31m◉\x1b[0m\n"), "{text}");
+        assert_eq!(text.matches("\x1b[").count(), 2);
+    }
+
+    #[test]
+    fn test_dump_frames_writes_one_file_per_frame() {
+        let dir = temp_dir("day1_frames");
+        let ring = Ring::new(8, CountMode::PassesTarget).with_radius(3);
+        let frames = animate(8, 6, &[Rotation::right(3)], CountMode::PassesTarget);
+        assert_eq!(dump_frames(&dir, &ring, frames.iter().copied()).unwrap(), 4);
+        assert_eq!(fs::read_to_string(dir.join("frame-000003.txt")).unwrap(), ring.render(&frames[2]));
+        assert!(!dir.join("frame-000005.txt").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,410 @@
+use aoc_common::error::column_of;
+use aoc_common::{Error, Interval, ParseError, Solution};
+use std::fmt::Display;
+
+/// Parses `start-end` ranges separated by commas and/or line breaks, e.g. "11-22,95-115\n998-1012".
+///
+/// Blank lines are skipped, and any line may end in a comma, as copied inputs often do, but an empty range
+/// between two commas is rejected.
+pub fn parse_ranges(input: &str) -> Result<Vec<Interval>, ParseError> {
+    let lines = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+    let mut ranges = Vec::new();
+    for (index, line) in lines {
+        let items: Vec<&str> = line.split(',').collect();
+        for (number, &item) in items.iter().enumerate() {
+            let trailing = number > 0 && number + 1 == items.len();
+            if trailing && item.trim().is_empty() {
+                continue;
+            }
+            ranges.push(parse_range(line, item).map_err(|err| err.at_line(index + 1))?);
+        }
+    }
+    Ok(ranges)
+}
+
+/// Parses one `start-end` range found in `line`, rejecting empty and reversed ranges.
+fn parse_range(line: &str, item: &str) -> Result<Interval, ParseError> {
+    if item.trim().is_empty() {
+        return Err(ParseError::new("empty range, expected start-end").at_column(column_of(line, item)));
+    }
+    let range = Interval::parse(item).map_err(|err| err.shift_columns(column_of(line, item) - 1))?;
+    if range.start > range.end {
+        return Err(ParseError::invalid(line, item.trim(), "range starts after it ends"));
+    }
+    Ok(range)
+}
+
+/// Number of decimal digits in the largest `i64`.
+const MAX_DIGITS: u32 = 19;
+
+/// Multiplier that repeats a block of `block_length` digits to fill `length` digits, e.g. 10101 for a block
+/// of 2 digits in 6.
+fn repeat_multiplier(length: u32, block_length: u32) -> i128 {
+    (10_i128.pow(length) - 1) / (10_i128.pow(block_length) - 1)
+}
+
+/// IDs in `start..=end` made of a block of digits repeated some number of times allowed by `repeats`, each
+/// once, shortest first but otherwise not in order.
+///
+/// Rather than testing every number in the range, this multiplies every block that could fit by its
+/// [`repeat_multiplier`], so it takes time in proportion to the number of IDs found however wide the range.
+fn generate_repeated_ids(start: i64, end: i64, repeats: fn(u32) -> bool) -> impl Iterator<Item = i64> {
+    let (start, end) = (i128::from(start), i128::from(end));
+    let block_lengths = move |length: u32| {
+        (1..length).filter(move |&block_length| {
+            length.is_multiple_of(block_length) && repeats(length / block_length)
+        })
+    };
+    (2..=MAX_DIGITS).flat_map(move |length| {
+        block_lengths(length).flat_map(move |block_length| {
+            let multiplier = repeat_multiplier(length, block_length);
+            let smallest = ((start.max(0) + multiplier - 1) / multiplier).max(10_i128.pow(block_length - 1));
+            let largest = (end / multiplier).min(10_i128.pow(block_length) - 1);
+            // A block repeated can itself be a repeated block, e.g. 222222 is 2, 22 and 222 repeated, so each
+            // ID is only made from the shortest block that can make it.
+            let shorter_makes = move |id: i128| {
+                block_lengths(length)
+                    .take_while(|&shorter| shorter < block_length)
+                    .any(|shorter| id % 10_i128.pow(shorter) * repeat_multiplier(length, shorter) == id)
+            };
+            (smallest..=largest)
+                .map(move |block| block * multiplier)
+                .filter(move |&id| !shorter_makes(id))
+                .map(|id| id as i64)
+        })
+    })
+}
+
+/// Generates IDs in `start..=end` made only of some sequence of digits repeated twice e.g. 6464, as
+/// [`find_doubled_ids`] finds them.
+pub fn generate_doubled_ids(start: i64, end: i64) -> impl Iterator<Item = i64> {
+    generate_repeated_ids(start, end, |repeats| repeats == 2)
+}
+
+/// Generates IDs in `start..=end` made only of some sequence of digits repeated at least twice e.g.
+/// 121212, as [`find_invalid_ids`] finds them, though not in ascending order.
+pub fn generate_invalid_ids(start: i64, end: i64) -> impl Iterator<Item = i64> {
+    generate_repeated_ids(start, end, |repeats| repeats >= 2)
+}
+
+/// Finds IDs made only of some sequence of digits repeated twice e.g. 6464.
+///
+/// Checks every number in the range, so only suits narrow ranges; kept as a reference for
+/// [`generate_doubled_ids`].
+pub fn find_doubled_ids(start: i64, end: i64) -> Vec<i64> {
+    let mut invalid_ids = Vec::new();
+    for num in start..=end {
+        if num >= 10 && num.to_string().len() % 2 == 0 {
+            let num_str = num.to_string();
+            let mid = num_str.len() / 2;
+            let first_num = num_str[..mid].parse::<i64>().unwrap();
+            let second_num = num_str[mid..].parse::<i64>().unwrap();
+            if first_num == second_num {
+                invalid_ids.push(num);
+            }
+        }
+    }
+    invalid_ids
+}
+
+/// Finds IDs made only of some sequence of digits repeated at least twice e.g. 121212.
+///
+/// Checks every number in the range, so only suits narrow ranges; kept as a reference for
+/// [`generate_invalid_ids`].
+pub fn find_invalid_ids(start: i64, end: i64) -> Vec<i64> {
+    let mut invalid_ids = Vec::new();
+    for num in start..=end {
+        if num >= 10 {
+            let num_len = num.to_string().len();
+            for i in 1..=num_len / 2 {
+                // can number be split into equal parts of length i?
+                if num_len % i == 0 {
+                    let mut num_parts = vec![];
+                    let mut j = 0;
+                    while j + i <= num.to_string().len() {
+                        num_parts.push(num.to_string()[j..j+i].parse::<i64>().unwrap());
+                        j += i;
+                    }
+                    if !num_parts.is_empty() && num_parts.iter().all(|&x| x == num_parts[0]) {
+                        invalid_ids.push(num);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    invalid_ids
+}
+
+/// Sums the IDs found by `find_ids` across every range, in 128 bits since a few IDs near the top of the
+/// 64 bit range already add up to more.
+///
+/// # Returns
+///
+/// The sum, or `None` if even 128 bits cannot hold it.
+pub fn sum_invalid_ids<I>(ranges: &[Interval], find_ids: impl Fn(i64, i64) -> I) -> Option<i128>
+where
+    I: IntoIterator<Item = i64>,
+{
+    ranges
+        .iter()
+        .flat_map(|range| find_ids(range.start, range.end))
+        .try_fold(0_i128, |sum, id| sum.checked_add(i128::from(id)))
+}
+
+fn too_big() -> Error {
+    Error::Solve("the invalid IDs add up to more than 128 bits can hold".to_string())
+}
+
+/// The gift shop's product ID ranges.
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    type Input = Vec<Interval>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        sum_invalid_ids(input, generate_doubled_ids).ok_or_else(too_big)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        sum_invalid_ids(input, generate_invalid_ids).ok_or_else(too_big)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doubled_11_22() {
+        // Should be invalid for 11 and 22 (11 is odd, but by supplied instructions treat as invalid for test purposes)
+        let expected = vec![11, 22];
+        let actual = find_doubled_ids(11, 22);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_95_115() {
+        // Only 99 is invalid in this range (99 is odd, should be invalid for test, though logic in main would not consider odd)
+        let expected = vec![99];
+        let actual = find_doubled_ids(95, 115);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_998_1012() {
+        let expected = vec![1010];
+        let actual = find_doubled_ids(998, 1012);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_1188511880_1188511890() {
+        let expected = vec![1188511885];
+        let actual = find_doubled_ids(1188511880, 1188511890);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_222220_222224() {
+        let expected = vec![222222];
+        let actual = find_doubled_ids(222220, 222224);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_1698522_1698528() {
+        // Contains no invalid IDs
+        let expected: Vec<i64> = vec![];
+        let actual = find_doubled_ids(1698522, 1698528);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_446443_446449() {
+        let expected = vec![446446];
+        let actual = find_doubled_ids(446443, 446449);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_doubled_38593856_38593862() {
+        let expected = vec![38593859];
+        let actual = find_doubled_ids(38593856, 38593862);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_11_22() {
+        // Should be invalid for 11 and 22
+        let expected = vec![11, 22];
+        let actual = find_invalid_ids(11, 22);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_95_115() {
+        // Should be invalid for 99 and 111
+        let expected = vec![99, 111];
+        let actual = find_invalid_ids(95, 115);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_998_1012() {
+        // Should be invalid for 999 and 1010
+        let expected = vec![999, 1010];
+        let actual = find_invalid_ids(998, 1012);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_1188511880_1188511890() {
+        let expected = vec![1188511885];
+        let actual = find_invalid_ids(1188511880, 1188511890);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_222220_222224() {
+        let expected = vec![222222];
+        let actual = find_invalid_ids(222220, 222224);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_1698522_1698528() {
+        // Contains no invalid IDs
+        let expected: Vec<i64> = vec![];
+        let actual = find_invalid_ids(1698522, 1698528);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_446443_446449() {
+        let expected = vec![446446];
+        let actual = find_invalid_ids(446443, 446449);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_38593856_38593862() {
+        let expected = vec![38593859];
+        let actual = find_invalid_ids(38593856, 38593862);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_565653_565659() {
+        let expected = vec![565656];
+        let actual = find_invalid_ids(565653, 565659);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_824824821_824824827() {
+        let expected = vec![824824824];
+        let actual = find_invalid_ids(824824821, 824824827);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_repeated_2121212118_2121212124() {
+        let expected = vec![2121212121];
+        let actual = find_invalid_ids(2121212118, 2121212124);
+        assert_eq!(actual, expected);
+    }
+
+    fn sorted(ids: impl Iterator<Item = i64>) -> Vec<i64> {
+        let mut ids: Vec<i64> = ids.collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn test_generators_match_brute_force() {
+        let mut ranges = vec![(-50, 50), (0, 0), (11, 11), (12, 21), (1, 100_000), (998, 1012)];
+        ranges.extend([(1188511880, 1188511890), (2121212118, 2121212124), (999_990, 1_000_020)]);
+        // Windows across a boundary between lengths, and every window of width 500 up to 50,000.
+        ranges.extend([(99_999_000, 100_001_000), (9_999_999_000, 10_000_001_000)]);
+        ranges.extend((0..50_000).step_by(500).map(|start| (start, start + 499)));
+        for (start, end) in ranges {
+            let (doubled, invalid) = (generate_doubled_ids(start, end), generate_invalid_ids(start, end));
+            assert_eq!(sorted(doubled), find_doubled_ids(start, end), "{start}-{end}");
+            assert_eq!(sorted(invalid), find_invalid_ids(start, end), "{start}-{end}");
+        }
+    }
+
+    #[test]
+    fn test_generators_handle_huge_ranges() {
+        // 9 blocks of one digit doubled, 90 of two digits, and so on up to 90,000 of five.
+        assert_eq!(generate_doubled_ids(1, 10_000_000_000).count(), 99_999);
+        // With 19 digits only single digits can repeat, and 9 repeated is too big for an i64.
+        let ids = sorted(generate_invalid_ids(1_000_000_000_000_000_000, i64::MAX));
+        let repdigits: Vec<i64> = (1..=8).map(|digit| digit * 1_111_111_111_111_111_111).collect();
+        assert_eq!(ids, repdigits);
+        assert_eq!(generate_invalid_ids(i64::MAX, i64::MAX).count(), 0);
+        assert_eq!(generate_invalid_ids(5, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_sums_past_64_bits() {
+        // 999999990999999990 up to 999999999999999999, ten IDs that add up to more than i64::MAX.
+        let ranges = Day2::parse("999999990999999990-999999999999999999").unwrap();
+        let expected: i128 = (999_999_990..=999_999_999).map(|block| block * 1_000_000_001).sum();
+        assert_eq!(expected, 9_999_999_954_999_999_945);
+        assert_eq!(Day2::part1(&ranges).unwrap().to_string(), expected.to_string());
+        assert_eq!(Day2::part2(&ranges).unwrap().to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_parse_ranges_reports_column() {
+        let err = parse_ranges("11-22,95-1x5").unwrap_err();
+        assert_eq!(err.to_string(), "1:10: invalid end of range (invalid digit found in string): \"1x5\"");
+    }
+
+    #[test]
+    fn test_parse_ranges_across_lines() {
+        let ranges = parse_ranges("11-22,95-115,\n\n 998-1012\n1188511880-1188511890, 222220-222224\n");
+        let expected = [(11, 22), (95, 115), (998, 1012), (1188511880, 1188511890), (222220, 222224)];
+        let expected = expected.map(|(start, end)| Interval::new(start, end));
+        assert_eq!(ranges.unwrap(), expected);
+        assert_eq!(parse_ranges("").unwrap(), []);
+    }
+
+    #[test]
+    fn test_parse_ranges_allows_trailing_comma() {
+        let expected = [Interval::new(11, 22), Interval::new(95, 115)];
+        assert_eq!(parse_ranges("11-22,95-115,").unwrap(), expected);
+        assert_eq!(parse_ranges("11-22,95-115, \n\n").unwrap(), expected);
+        assert_eq!(parse_ranges("11-22,\n95-115,").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_parse_ranges_rejects_bad_items() {
+        let err = parse_ranges("11-22\n95-115,,998-1012").unwrap_err();
+        assert_eq!(err.to_string(), "2:8: empty range, expected start-end");
+        let err = parse_ranges(",11-22").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(1)));
+        let err = parse_ranges("11-22\n5-3").unwrap_err();
+        assert_eq!(err.to_string(), "2:1: range starts after it ends: \"5-3\"");
+        let err = parse_ranges("11-22,  abc").unwrap_err();
+        assert_eq!(err.to_string(), "1:9: expected a range like start-end: \"abc\"");
+        let err = parse_ranges("11-22\n1-2,x-4").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_deref()), (Some(2), Some(5), Some("x")));
+    }
+
+    #[test]
+    fn test_example_sums() {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        let ranges = Day2::parse(input).unwrap();
+        assert_eq!(Day2::part1(&ranges).unwrap().to_string(), "1227775554");
+        assert_eq!(Day2::part2(&ranges).unwrap().to_string(), "4174379265");
+    }
+}
@@ -0,0 +1,188 @@
+use aoc_common::input::{parse_digits, parse_lines_with};
+use aoc_common::{Error, ParseError, Solution};
+use std::fmt::Display;
+
+/// Finds the largest joltage made by turning on exactly `length` batteries of a bank, in order.
+///
+/// # Panics
+///
+/// If the bank has fewer than `length` batteries.
+pub fn max_jolt(digits: &[u32], length: usize) -> u64 {
+    let mut start_pos = 0;
+    let mut batteries: Vec<u32> = Vec::new();
+    for i in (1..=length).rev() {
+        let max_val = digits[start_pos..(digits.len() - i + 1)]
+            .iter()
+            .max()
+            .copied()
+            .unwrap();
+
+        batteries.push(max_val);
+        start_pos = digits
+            .iter()
+            .copied()
+            .enumerate()
+            .position(|(idx, v)| v == max_val && idx >= start_pos)
+            .unwrap() + 1;
+    }
+
+    let jolt = batteries.iter().fold(0, |acc, x| acc * 10 + *x as u64);
+    jolt
+}
+
+/// Finds the largest joltage of a bank as [`max_jolt`] does, or reports that it has too few batteries.
+pub fn checked_jolt(digits: &[u32], length: usize) -> Result<u64, ParseError> {
+    if digits.len() < length {
+        return Err(ParseError::new(format!(
+            "bank has {} batteries but {length} must be turned on",
+            digits.len()
+        )));
+    }
+    Ok(max_jolt(digits, length))
+}
+
+/// Finds the largest joltage of a bank written as a line of digits e.g. "811111111111119".
+pub fn find_jolt(line: &str, length: usize) -> Result<u64, ParseError> {
+    let digits = parse_digits(line)?;
+    checked_jolt(&digits, length).map_err(|err| err.with_text(line))
+}
+
+/// Sums the largest joltage of every bank, or reports the first bank with fewer than `length` batteries.
+pub fn total_jolt(banks: &[Vec<u32>], length: usize) -> Result<u64, ParseError> {
+    banks.iter().enumerate().try_fold(0, |sum, (index, bank)| {
+        let jolt = checked_jolt(bank, length).map_err(|_| {
+            ParseError::new(format!(
+                "bank {} has {} batteries but {length} must be turned on",
+                index + 1,
+                bank.len()
+            ))
+        })?;
+        Ok(sum + jolt)
+    })
+}
+
+/// Banks of batteries, one joltage digit per battery.
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines_with(input, parse_digits)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(total_jolt(input, 2)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(total_jolt(input, 12)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_jolt_rejects_bad_banks() {
+        let err = find_jolt("98x1", 2).unwrap_err();
+        assert_eq!((err.column, err.text.as_deref()), (Some(3), Some("x")));
+        assert!(find_jolt("9", 2).is_err());
+    }
+
+    #[test]
+    fn test_parse_reports_line_of_bad_digit() {
+        let err = Day3::parse("987654321111111\n81?\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:3: expected a digit: \"?\"");
+    }
+
+    #[test]
+    fn test_short_banks_parse_and_fail_only_the_part_needing_more_batteries() {
+        let banks = Day3::parse("987654321111111\n811\n").unwrap();
+        assert_eq!(Day3::part1(&banks).unwrap().to_string(), "179");
+        let Err(err) = Day3::part2(&banks) else {
+            panic!("part 2 needs 12 batteries in every bank");
+        };
+        assert_eq!(err.to_string(), "bank 2 has 3 batteries but 12 must be turned on");
+        assert_eq!(total_jolt(&banks, 3), Ok(987 + 811));
+    }
+
+    #[test]
+    fn test_two_batteries_find_jolt_987654321111111() {
+        // In 987654321111111, you can make the largest joltage possible, 98, by turning on the first two batteries.
+        assert_eq!(find_jolt("987654321111111", 2), Ok(98));
+    }
+
+    #[test]
+    fn test_two_batteries_find_jolt_811111111111119() {
+        // In 811111111111119, you can make the largest joltage possible by turning on the batteries labeled 8 and 9, producing 89 jolts.
+        assert_eq!(find_jolt("811111111111119", 2), Ok(89));
+    }
+
+    #[test]
+    fn test_two_batteries_find_jolt_234234234234278() {
+        // In 234234234234278, you can make 78 by turning on the last two batteries (marked 7 and 8).
+        assert_eq!(find_jolt("234234234234278", 2), Ok(78));
+    }
+
+    #[test]
+    fn test_two_batteries_find_jolt_818181911112111() {
+        // In 818181911112111, the largest joltage you can produce is 92.
+        assert_eq!(find_jolt("818181911112111", 2), Ok(92));
+    }
+
+    #[test]
+    fn test_two_batteries_jolt_sum() {
+        // sum should be 357
+        let lines = [
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+        let jolts = lines.iter().map(|line| find_jolt(line, 2).unwrap()).collect::<Vec<u64>>();
+        let sum: u64 = jolts.iter().sum();
+        assert_eq!(sum, 357);
+    }
+
+    #[test]
+    fn test_twelve_batteries_find_jolt_987654321111111() {
+        // In 987654321111111, the largest joltage can be found by turning on everything except some 1s at the end to produce 987654321111.
+        assert_eq!(find_jolt("987654321111111", 12), Ok(987654321111));
+    }
+
+    #[test]
+    fn test_twelve_batteries_find_jolt_811111111111119() {
+        // In 811111111111119, the largest joltage can be found by turning on everything except some 1s, producing 811111111119.
+        assert_eq!(find_jolt("811111111111119", 12), Ok(811111111119));
+    }
+
+    #[test]
+    fn test_twelve_batteries_find_jolt_234234234234278() {
+        // In 234234234234278, the largest joltage can be found by turning on everything except a 2 battery, a 3 battery, and another 2 battery near the start to produce 434234234278.
+        assert_eq!(find_jolt("234234234234278", 12), Ok(434234234278));
+    }
+
+    #[test]
+    fn test_twelve_batteries_find_jolt_818181911112111() {
+        // In 818181911112111, the joltage 888911112111 is produced by turning on everything except some 1s near the front.
+        assert_eq!(find_jolt("818181911112111", 12), Ok(888911112111));
+    }
+
+    #[test]
+    fn test_twelve_batteries_jolt_sum() {
+        // sum should be 3121910778619
+        let lines = [
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+        let jolts = lines.iter().map(|line| find_jolt(line, 12).unwrap()).collect::<Vec<u64>>();
+        let sum: u64 = jolts.iter().sum();
+        assert_eq!(sum, 3121910778619);
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_common::{Error, Grid, ParseError, Solution};
+use std::fmt::Display;
+
+/// Paper roll queries on top of the shared grid, where '@' marks a roll.
+pub trait PaperRolls {
+    fn number_adjacent_rolls(&self, x: i32, y: i32) -> usize;
+    fn accessible_rolls(&self) -> Vec<(i32, i32)>;
+    fn remove_accessible_round(&mut self) -> bool;
+    fn recursively_remove_accessible(&mut self);
+}
+
+impl PaperRolls for Grid {
+    fn number_adjacent_rolls(&self, x: i32, y: i32) -> usize {
+        self.count_adjacent(x, y, '@')
+    }
+
+    fn accessible_rolls(&self) -> Vec<(i32, i32)> {
+        self.positions()
+            .filter(|&(x, y)| self.get(x, y) == Some('@') && self.number_adjacent_rolls(x, y) < 4)
+            .collect()
+    }
+
+    fn remove_accessible_round(&mut self) -> bool {
+        let to_remove = self.accessible_rolls();
+        if to_remove.is_empty() {
+            return false;
+        }
+        for (x, y) in to_remove {
+            self.set(x, y, '.');
+        }
+        true
+    }
+
+    fn recursively_remove_accessible(&mut self) {
+        if self.remove_accessible_round() {
+            self.recursively_remove_accessible();
+        }
+    }
+}
+
+/// The printing department's grid of paper rolls.
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input)?;
+        for (index, line) in input.lines().enumerate() {
+            if let Some((offset, cell)) = line.char_indices().find(|&(_, c)| c != '@' && c != '.') {
+                let cell = &line[offset..offset + cell.len_utf8()];
+                return Err(ParseError::invalid(line, cell, "expected '@' or '.'").at_line(index + 1));
+            }
+        }
+        Ok(grid)
+    }
+
+    /// The number of rolls a forklift can reach.
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.accessible_rolls().len())
+    }
+
+    /// The number of rolls removed once nothing more is accessible.
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        let mut grid = input.clone();
+        grid.recursively_remove_accessible();
+        Ok(input.count('@') - grid.count('@'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn sample_input() -> &'static str {
+        "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+"
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_cells() {
+        let err = Day4::parse("..@\n.#@\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:2: expected '@' or '.': \"#\"");
+    }
+
+    #[test]
+    fn test_number_adjacent_rolls_some_positions() {
+        let grid = Grid::parse(sample_input()).unwrap();
+
+        // At (0,0), adjacents are: (0,1),(1,0),(1,1)
+        assert_eq!(grid.number_adjacent_rolls(0, 0), 2); // Only (1,0) and (1,1) are '@'
+
+        // At (2,0): Should have 3 adjacent rolls at (1,1), (1,2) and (3,0)
+        assert_eq!(grid.number_adjacent_rolls(2, 0), 3);
+
+        // At (1,1): Surrounded by more rolls
+        assert_eq!(grid.number_adjacent_rolls(1, 1), 6);
+    }
+
+    #[test]
+    fn test_accessible_rolls_count_matches_spec() {
+        let grid = Grid::parse(sample_input()).unwrap();
+        assert_eq!(
+            grid.accessible_rolls().len(),
+            13,
+            "Should find exactly 13 accessible rolls"
+        );
+    }
+
+    #[test]
+    fn test_accessible_rolls_positions() {
+        let grid = Grid::parse(sample_input()).unwrap();
+        // Positions of accessible rolls '@' with <4 adjacent '@', from spec
+        let accessible_pos = [
+            (2, 0),
+            (3, 0),
+            (5, 0),
+            (6, 0),
+            (8, 0),
+            (0, 1),
+            (6, 2),
+            (0, 4),
+            (9, 4),
+            (0, 7),
+            (0, 9),
+            (2, 9),
+            (8, 9),
+        ];
+        let expected: HashSet<_> = accessible_pos.into_iter().collect();
+        let actual: HashSet<_> = grid.accessible_rolls().into_iter().collect();
+        assert_eq!(expected, actual, "Accessible positions should match spec");
+    }
+
+    #[test]
+    fn test_part2_recursive_removal_exhausts_accessible_rolls() {
+        let mut grid = Grid::parse(sample_input()).unwrap();
+        grid.recursively_remove_accessible();
+        assert!(
+            grid.accessible_rolls().is_empty(),
+            "No accessible rolls should remain after recursive removal"
+        );
+    }
+    #[test]
+    fn test_part2_full_removal_count_matches_example() {
+        let raw = sample_input();
+        let mut grid = Grid::parse(raw).unwrap();
+        let initial_rolls = raw.chars().filter(|&c| c == '@').count();
+        grid.recursively_remove_accessible();
+        // Based on the new spec, we should remove 43 rolls in total.
+        let remaining_rolls = grid.count('@');
+        let removed_rolls = initial_rolls - remaining_rolls;
+        assert_eq!(removed_rolls, 43, "Should remove 43 rolls after full recursive removal");
+    }
+
+    #[test]
+    fn test_part2_remaining_grid_after_all_removals() {
+        let mut grid = Grid::parse(sample_input()).unwrap();
+        grid.recursively_remove_accessible();
+        // The remaining grid after all removals
+        // The final 10x10 shape should match this (from the example):
+        let expected = [
+            "..........",
+            "..........",
+            "..........",
+            "....@@....",
+            "...@@@@...",
+            "...@@@@@..",
+            "...@.@.@@.",
+            "...@@.@@@.",
+            "...@@@@@..",
+            "....@@@...",
+        ];
+        for (y, line) in expected.iter().enumerate() {
+            for (x, expected_ch) in line.chars().enumerate() {
+                let Some(val) = grid.get(x as i32, y as i32) else {
+                    continue;
+                };
+                assert_eq!(val, expected_ch, "Mismatch at ({x},{y})");
+            }
+        }
+    }
+}
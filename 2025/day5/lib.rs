@@ -0,0 +1,137 @@
+use aoc_common::input::{lines_before, parse_lines_with, split_sections};
+use aoc_common::interval::{merge_intervals, Interval};
+use aoc_common::{Error, ParseError, Solution};
+use std::fmt::Display;
+
+/// Fresh ingredient ID ranges and the available ingredient IDs of the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngredientsData {
+    pub ingredient_ranges: Vec<Interval>,
+    pub available_ingredients: Vec<i64>,
+}
+
+impl IngredientsData {
+    pub fn parse(input_string: &str) -> Result<Self, ParseError> {
+        let (range_block, available_block) = split_sections(input_string).ok_or_else(|| {
+            ParseError::new("input must contain a blank line separating the two sections")
+        })?;
+
+        let ingredient_ranges = parse_lines_with(range_block, Interval::parse)?;
+
+        let available_ingredients = parse_lines_with(available_block, |line| {
+            line.parse::<i64>()
+                .map_err(|err| ParseError::new(format!("invalid ingredient id ({err})")))
+        })
+        .map_err(|err| err.shift_lines(lines_before(input_string, available_block)))?;
+
+        Ok(IngredientsData {
+            ingredient_ranges,
+            available_ingredients,
+        })
+    }
+
+    /// The ingredient ranges with overlapping and adjoining ranges merged.
+    pub fn merged_ranges(&self) -> Vec<Interval> {
+        merge_intervals(&self.ingredient_ranges)
+    }
+
+    /// The available ingredients that fall into any of the ingredient ranges.
+    pub fn fresh_ingredients(&self) -> Vec<i64> {
+        let merged_ranges = self.merged_ranges();
+        let mut fresh_ingredients: Vec<i64> = Vec::new();
+        for ingredient in &self.available_ingredients {
+            for range in &merged_ranges {
+                if range.contains(*ingredient) {
+                    fresh_ingredients.push(*ingredient);
+                    break; // Once matched, don't check further ranges
+                }
+            }
+        }
+        fresh_ingredients
+    }
+}
+
+/// The cafeteria's fresh ingredient database.
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    type Input = IngredientsData;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        IngredientsData::parse(input)
+    }
+
+    /// The number of available ingredients that are fresh.
+    fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.fresh_ingredients().len())
+    }
+
+    /// The number of IDs considered fresh by the ranges.
+    fn part2(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(input.merged_ranges().iter().map(|range| range.len()).sum::<i64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    fn example_input() -> &'static str {
+        indoc! {"
+            3-5
+            10-14
+            16-20
+            12-18
+
+            1
+            5
+            8
+            11
+            17
+            32
+        "}
+    }
+    #[test]
+    fn test_parse_input_example() {
+        let ingredients_data = IngredientsData::parse(example_input()).unwrap();
+        assert_eq!(ingredients_data.ingredient_ranges.len(), 4);
+        assert_eq!(ingredients_data.ingredient_ranges[0].start, 3);
+        assert_eq!(ingredients_data.ingredient_ranges[0].end, 5);
+        assert_eq!(ingredients_data.ingredient_ranges[1].start, 10);
+        assert_eq!(ingredients_data.ingredient_ranges[1].end, 14);
+        assert_eq!(ingredients_data.ingredient_ranges[2].start, 16);
+        assert_eq!(ingredients_data.ingredient_ranges[2].end, 20);
+        assert_eq!(ingredients_data.ingredient_ranges[3].start, 12);
+        assert_eq!(ingredients_data.ingredient_ranges[3].end, 18);
+        assert_eq!(ingredients_data.available_ingredients, vec![1, 5, 8, 11, 17, 32]);
+    }
+
+    #[test]
+    fn test_parse_errors_report_lines_of_whole_input() {
+        let err = IngredientsData::parse("3-5\n10-x\n\n1\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:4: invalid end of range (invalid digit found in string): \"x\"");
+        let err = IngredientsData::parse("3-5\n10-14\n\n1\n5a\n").unwrap_err();
+        assert_eq!(err.to_string(), "5:1: invalid ingredient id (invalid digit found in string): \"5a\"");
+        assert!(IngredientsData::parse("3-5\n1\n").is_err());
+    }
+
+    #[test]
+    fn test_check_fresh_ingredients_example() {
+        let ingredients_data = IngredientsData::parse(example_input()).unwrap();
+        assert_eq!(ingredients_data.fresh_ingredients(), vec![5, 11, 17]);
+    }
+
+    #[test]
+    fn test_find_fresh_ingredient_ranges_example() {
+        let ingredients_data = IngredientsData::parse(example_input()).unwrap();
+        let mut sum = 0;
+        for range in ingredients_data.merged_ranges() {
+            sum += range.len();
+        }
+        println!("Sum: {sum}");
+        assert_eq!(sum, 14);
+    }
+}
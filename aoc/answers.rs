@@ -0,0 +1,231 @@
+use crate::registry::Day;
+use aoc_common::Unsolved;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the accepted answers file at the workspace root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Identifies one part of one puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PartKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+}
+
+impl fmt::Display for PartKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {} part {}", self.year, self.day, self.part)
+    }
+}
+
+/// Accepted puzzle answers, stored as TOML keyed by year, day and part:
+///
+/// ```toml
+/// [2025.day4]
+/// part1 = "1384"
+/// part2 = "8013"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<PartKey, String>,
+}
+
+impl Answers {
+    /// Default location of the answers file.
+    pub fn default_path() -> PathBuf {
+        aoc_common::locate::workspace_root().join(ANSWERS_FILE)
+    }
+
+    /// Loads answers from `path`, treating a missing file as having no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        for (key, answer) in parse_parts(raw)? {
+            let answer = match answer {
+                toml::Value::String(answer) => answer,
+                toml::Value::Integer(answer) => answer.to_string(),
+                _ => return Err(format!("answer for {key} should be a string")),
+            };
+            answers.insert(key, answer);
+        }
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Renders the answers as TOML, with years, days and parts in numeric order.
+    pub fn to_toml(&self) -> String {
+        render_parts(
+            self.answers
+                .iter()
+                .map(|(key, answer)| (*key, toml::Value::String(answer.clone()))),
+        )
+    }
+
+    pub fn get(&self, key: PartKey) -> Option<&str> {
+        self.answers.get(&key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: PartKey, answer: String) {
+        self.answers.insert(key, answer);
+    }
+}
+
+/// Reads a TOML file of per-part values, keyed like the answers file, into the values for each part.
+pub fn parse_parts(raw: &str) -> Result<Vec<(PartKey, toml::Value)>, String> {
+    let table: toml::Table = raw.parse().map_err(|err: toml::de::Error| err.message().to_string())?;
+    let mut parts = Vec::new();
+    for (year, days) in table {
+        let year = year
+            .parse::<u16>()
+            .map_err(|_| format!("invalid year {year:?}"))?;
+        let toml::Value::Table(days) = days else {
+            return Err(format!("expected a table of days for {year}"));
+        };
+        for (day, values) in days {
+            let day = day
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid day {day:?} in {year}, expected e.g. day4"))?;
+            let toml::Value::Table(values) = values else {
+                return Err(format!("expected a table of parts for {year} day {day}"));
+            };
+            for (part, value) in values {
+                let part = part
+                    .strip_prefix("part")
+                    .and_then(|part| part.parse::<u8>().ok())
+                    .ok_or_else(|| format!("invalid part {part:?} in {year} day {day}, expected e.g. part1"))?;
+                parts.push((PartKey { year, day, part }, value));
+            }
+        }
+    }
+    Ok(parts)
+}
+
+/// Renders per-part values as TOML, one table per day, in the order given.
+pub fn render_parts(parts: impl IntoIterator<Item = (PartKey, toml::Value)>) -> String {
+    let mut out = String::new();
+    let mut current = None;
+    for (key, value) in parts {
+        if current != Some((key.year, key.day)) {
+            if current.is_some() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[{}.day{}]\n", key.year, key.day));
+            current = Some((key.year, key.day));
+        }
+        out.push_str(&format!("part{} = {value}\n", key.part));
+    }
+    out
+}
+
+/// How a fresh answer compares with the accepted one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Matches,
+    Changed { expected: String },
+    /// No answer has been accepted for the part yet.
+    Unrecorded,
+}
+
+/// The answer a part produced on its real input, compared with the accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub key: PartKey,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.verdict {
+            Verdict::Matches => write!(f, "{}: ok ({})", self.key, self.answer),
+            Verdict::Changed { expected } => {
+                write!(f, "{}: CHANGED, expected {expected} but got {}", self.key, self.answer)
+            }
+            Verdict::Unrecorded => write!(f, "{}: no accepted answer ({})", self.key, self.answer),
+        }
+    }
+}
+
+/// Runs both parts of `day` on its real input and compares them with the accepted answers.
+///
+/// Parts that are not solved yet are left out; callers skip days with no input, see [`Day::has_input`].
+pub fn check_day(day: &Day, answers: &Answers) -> Result<Vec<Check>, String> {
+    let (year, number) = (day.year(), day.day());
+    let parsed = day.load(None)?;
+
+    let mut checks = Vec::new();
+    for part in 1..=2 {
+        let answer = day.solve(part, parsed.as_ref())?;
+        if Unsolved::is(&answer) {
+            continue;
+        }
+        let key = PartKey { year, day: number, part };
+        let verdict = match answers.get(key) {
+            Some(expected) if expected == answer => Verdict::Matches,
+            Some(expected) => Verdict::Changed {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unrecorded,
+        };
+        checks.push(Check { key, answer, verdict });
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::registry;
+
+    fn key(year: u16, day: u8, part: u8) -> PartKey {
+        PartKey { year, day, part }
+    }
+
+    #[test]
+    fn test_round_trip_orders_days_numerically() {
+        let mut answers = Answers::default();
+        answers.insert(key(2025, 10, 1), "42".to_string());
+        answers.insert(key(2025, 2, 2), "b".to_string());
+        answers.insert(key(2025, 2, 1), "a \"quoted\"".to_string());
+        let raw = answers.to_toml();
+        assert_eq!(
+            raw,
+            "[2025.day2]\npart1 = 'a \"quoted\"'\npart2 = \"b\"\n\n[2025.day10]\npart1 = \"42\"\n"
+        );
+        assert_eq!(Answers::parse(&raw), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_accepts_integer_answers_and_rejects_bad_keys() {
+        let answers = Answers::parse("[2025.day4]\npart1 = 1384\n").unwrap();
+        assert_eq!(answers.get(key(2025, 4, 1)), Some("1384"));
+        assert!(Answers::parse("[2025.four]\npart1 = 1\n").is_err());
+        assert!(Answers::parse("[2025.day4]\nthird = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_recorded_answers_still_hold() {
+        let answers = Answers::load(&Answers::default_path()).unwrap();
+        for day in registry().into_iter().filter(Day::has_input) {
+            for check in check_day(&day, &answers).unwrap() {
+                assert_eq!(check.verdict, Verdict::Matches, "{check}");
+            }
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use crate::registry::Day;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hint::black_box;
+use std::io::Cursor;
+use std::time::{Duration, Instant};
+
+/// Summary of the timings of one stage, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Stats {
+    /// Summarises a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter().map(|sample| sample.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+        let middle = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[middle - 1] + nanos[middle]) / 2
+        } else {
+            nanos[middle]
+        };
+        Self {
+            min_ns: nanos[0],
+            median_ns,
+            mean_ns: nanos.iter().sum::<u64>() / nanos.len() as u64,
+        }
+    }
+}
+
+/// Times `stage` `iterations` times after `warmup` untimed runs.
+pub fn measure<T, F: FnMut() -> T>(iterations: usize, warmup: usize, mut stage: F) -> Stats {
+    for _ in 0..warmup {
+        black_box(stage());
+    }
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(stage());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Stage timings of one day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub year: u16,
+    pub day: u8,
+    /// Size of the input the stages were timed on; 0 in reports saved before it was recorded.
+    #[serde(default)]
+    pub input_bytes: u64,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    /// Solving both parts while reading the input, for days that can stream it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<Stats>,
+}
+
+impl DayTimings {
+    pub fn stages(&self) -> Vec<(&'static str, Stats)> {
+        let mut stages = vec![
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ];
+        stages.extend(self.stream.map(|stream| ("stream", stream)));
+        stages
+    }
+
+    /// How fast `stage` got through the input at its median time, for the stages that read it; the
+    /// parts only see the parsed input.
+    pub fn throughput(&self, stage: &str) -> Option<String> {
+        if !matches!(stage, "parse" | "stream") {
+            return None;
+        }
+        let (_, stats) = self.stages().into_iter().find(|&(name, _)| name == stage)?;
+        Some(format_throughput(self.input_bytes, stats.median_ns))
+    }
+}
+
+/// Machine readable result of an `aoc bench` run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub warmup: usize,
+    pub days: Vec<DayTimings>,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("bench reports always serialise")
+    }
+
+    pub fn from_json(raw: &str) -> Result<Self, String> {
+        serde_json::from_str(raw).map_err(|err| err.to_string())
+    }
+}
+
+/// Benchmarks the parse, part 1 and part 2 stages of `day` on its real input, and streaming the input
+/// for days that can.
+pub fn bench_day(day: &Day, iterations: usize, warmup: usize) -> Result<DayTimings, String> {
+    let (year, number) = (day.year(), day.day());
+    let (source, raw) = day
+        .read_input(None)
+        .map_err(|err| format!("Failed to read input for {year} day {number}: {err}"))?;
+    let parsed = day.parse(&source, &raw)?;
+
+    for part in 1..=2 {
+        day.solve(part, parsed.as_ref())?;
+    }
+
+    let parse = measure(iterations, warmup, || day.solution.parse(&raw));
+    let part1 = measure(iterations, warmup, || day.solution.solve(1, parsed.as_ref()));
+    let part2 = measure(iterations, warmup, || day.solution.solve(2, parsed.as_ref()));
+    if let Some(stream) = day.stream {
+        stream(&mut Cursor::new(raw.as_bytes()))
+            .map_err(|err| format!("Failed to stream input for {year} day {number}: {err}"))?;
+    }
+    let stream = day
+        .stream
+        .map(|stream| measure(iterations, warmup, || stream(&mut Cursor::new(raw.as_bytes()))));
+    Ok(DayTimings {
+        year,
+        day: number,
+        input_bytes: raw.len() as u64,
+        parse,
+        part1,
+        part2,
+        stream,
+    })
+}
+
+/// A stage whose median time moved compared with the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Change in median time as a percentage of the baseline; positive is slower.
+    pub fn change_percent(&self) -> f64 {
+        (self.current_ns as f64 - self.baseline_ns as f64) / self.baseline_ns.max(1) as f64 * 100.0
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} {}: {} -> {} ({:+.1}%){}",
+            self.year,
+            self.day,
+            self.stage,
+            format_ns(self.baseline_ns),
+            format_ns(self.current_ns),
+            self.change_percent(),
+            if self.regressed { " REGRESSED" } else { "" }
+        )
+    }
+}
+
+/// Compares median stage times with a baseline report.
+///
+/// A stage regressed if it got slower by more than `threshold_percent`. Days missing from
+/// either report are left out.
+pub fn compare(baseline: &Report, current: &Report, threshold_percent: f64) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+    for timings in &current.days {
+        let Some(previous) = baseline
+            .days
+            .iter()
+            .find(|previous| previous.year == timings.year && previous.day == timings.day)
+        else {
+            continue;
+        };
+        for (stage, stats) in timings.stages() {
+            let previous_stats = previous.stages().into_iter().find(|&(name, _)| name == stage);
+            let Some((_, previous_stats)) = previous_stats else {
+                continue;
+            };
+            let mut comparison = Comparison {
+                year: timings.year,
+                day: timings.day,
+                stage,
+                baseline_ns: previous_stats.median_ns,
+                current_ns: stats.median_ns,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change_percent() > threshold_percent;
+            comparisons.push(comparison);
+        }
+    }
+    comparisons
+}
+
+/// Renders nanoseconds with a unit suited to their size, e.g. "1.25ms".
+pub fn format_ns(nanos: u64) -> String {
+    let nanos = nanos as f64;
+    if nanos >= 1e9 {
+        format!("{:.2}s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else {
+        format!("{nanos}ns")
+    }
+}
+
+/// Renders how fast `bytes` of input went through a stage taking `nanos`, e.g. "12.50 MB/s".
+pub fn format_throughput(bytes: u64, nanos: u64) -> String {
+    let per_second = bytes as f64 / nanos.max(1) as f64 * 1e9;
+    if per_second >= 1e9 {
+        format!("{:.2} GB/s", per_second / 1e9)
+    } else if per_second >= 1e6 {
+        format!("{:.2} MB/s", per_second / 1e6)
+    } else if per_second >= 1e3 {
+        format!("{:.2} kB/s", per_second / 1e3)
+    } else {
+        format!("{per_second:.0} B/s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::find;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+        }
+    }
+
+    fn report(parse: u64, part1: u64, part2: u64) -> Report {
+        Report {
+            iterations: 10,
+            warmup: 1,
+            days: vec![DayTimings {
+                year: 2025,
+                day: 4,
+                input_bytes: 1000,
+                parse: stats(parse),
+                part1: stats(part1),
+                part2: stats(part2),
+                stream: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [30, 10, 20, 100].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Stats {
+                min_ns: 10,
+                median_ns: 25,
+                mean_ns: 40
+            }
+        );
+        let samples = [30, 10, 20].map(Duration::from_nanos);
+        assert_eq!(Stats::from_samples(&samples).median_ns, 20);
+    }
+
+    #[test]
+    fn test_measure_runs_warmup_and_iterations() {
+        let mut runs = 0;
+        measure(5, 2, || runs += 1);
+        assert_eq!(runs, 7);
+    }
+
+    #[test]
+    fn test_report_json_round_trip() {
+        let report = report(1, 2, 3);
+        assert_eq!(Report::from_json(&report.to_json()), Ok(report));
+    }
+
+    #[test]
+    fn test_compare_flags_regressions_over_threshold() {
+        let comparisons = compare(&report(100, 100, 100), &report(105, 120, 80), 10.0);
+        let regressed: Vec<(&str, bool)> = comparisons.iter().map(|c| (c.stage, c.regressed)).collect();
+        assert_eq!(
+            regressed,
+            vec![("parse", false), ("part1", true), ("part2", false)]
+        );
+        assert_eq!(
+            comparisons[1].to_string(),
+            "2025 day 4 part1: 100ns -> 120ns (+20.0%) REGRESSED"
+        );
+    }
+
+    #[test]
+    fn test_report_without_input_bytes_still_loads() {
+        let mut json: serde_json::Value = serde_json::from_str(&report(1, 2, 3).to_json()).unwrap();
+        json["days"][0].as_object_mut().unwrap().remove("input_bytes");
+        let report = Report::from_json(&json.to_string()).unwrap();
+        assert_eq!(report.days[0].input_bytes, 0);
+    }
+
+    #[test]
+    fn test_compare_skips_stages_missing_from_baseline() {
+        let mut current = report(100, 100, 100);
+        current.days[0].stream = Some(stats(500));
+        let comparisons = compare(&report(100, 100, 100), &current, 10.0);
+        let stages: Vec<&str> = comparisons.iter().map(|c| c.stage).collect();
+        assert_eq!(stages, ["parse", "part1", "part2"]);
+        assert_eq!(compare(&current, &current, 10.0).len(), 4);
+    }
+
+    #[test]
+    fn test_throughput_only_for_stages_reading_input() {
+        let mut timings = report(1_000, 10, 10).days.remove(0);
+        timings.stream = Some(stats(2_000));
+        assert_eq!(timings.throughput("parse").as_deref(), Some("1.00 GB/s"));
+        assert_eq!(timings.throughput("stream").as_deref(), Some("500.00 MB/s"));
+        assert_eq!(timings.throughput("part1"), None);
+        timings.stream = None;
+        assert_eq!(timings.throughput("stream"), None);
+    }
+
+    #[test]
+    fn test_format_throughput() {
+        assert_eq!(format_throughput(1000, 1000), "1.00 GB/s");
+        assert_eq!(format_throughput(25_000, 2_000_000), "12.50 MB/s");
+        assert_eq!(format_throughput(3, 1_000_000), "3.00 kB/s");
+        assert_eq!(format_throughput(0, 0), "0 B/s");
+    }
+
+    #[test]
+    fn test_bench_day_times_every_stage() {
+        let day = find(2025, 4).unwrap();
+        let timings = bench_day(&day, 1, 0).unwrap();
+        assert_eq!((timings.year, timings.day), (2025, 4));
+        assert!(timings.part2.min_ns > 0);
+        assert_eq!(timings.input_bytes, day.read_input(None).unwrap().1.len() as u64);
+        assert_eq!(timings.stream, None);
+        assert!(bench_day(&find(2025, 1).unwrap(), 1, 0).unwrap().stream.is_some());
+    }
+}
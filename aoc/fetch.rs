@@ -0,0 +1,108 @@
+use crate::http::{Http, Site};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Outcome of fetching a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was already saved, so nothing was requested.
+    Cached(PathBuf),
+}
+
+impl fmt::Display for Fetched {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fetched::Downloaded(path) => write!(f, "saved to {}", path.display()),
+            Fetched::Cached(path) => write!(f, "already saved at {}", path.display()),
+        }
+    }
+}
+
+/// Downloads a day's puzzle input to `path`, unless a file is already there.
+///
+/// Inputs never change once published, so a saved input is never downloaded again.
+pub fn fetch_input<H: Http>(site: &Site<H>, year: u16, day: u8, path: &Path) -> Result<Fetched, String> {
+    if path.is_file() {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+    let input = site
+        .input(year, day)
+        .map_err(|err| format!("Failed to download input for {year} day {day}: {err}"))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("Failed to create {}: {err}", dir.display()))?;
+    }
+    fs::write(path, input).map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{Response, Ureq};
+    use crate::mock_server::MockServer;
+    use aoc_common::testing::temp_dir;
+    use aoc_common::InputLocator;
+
+    /// Fails the test if any request is made.
+    struct Offline;
+
+    impl Http for Offline {
+        fn get(&self, url: &str, _session: &str) -> Result<Response, String> {
+            panic!("unexpected request to {url}")
+        }
+
+        fn post_form(&self, url: &str, _session: &str, _form: &[(&str, &str)]) -> Result<Response, String> {
+            panic!("unexpected request to {url}")
+        }
+    }
+
+    #[test]
+    fn test_downloads_where_the_locator_looks() {
+        let server = MockServer::start(vec![("GET", "/2025/day/6/input", 200, "123 328\n")]);
+        let site = Site::new(Ureq::new(), server.url()).with_session(Some("abc123".to_string()));
+        let input_dir = temp_dir("fetch_download");
+        let locator = InputLocator::new(2025, 6).with_input_dir(Some(input_dir.clone()));
+
+        let path = locator.store_path();
+        assert_eq!(fetch_input(&site, 2025, 6, &path), Ok(Fetched::Downloaded(path.clone())));
+        assert_eq!(locator.read().unwrap(), "123 328\n");
+
+        // A second fetch finds the saved input and leaves the server alone.
+        assert_eq!(fetch_input(&site, 2025, 6, &path), Ok(Fetched::Cached(path)));
+        assert_eq!(server.requests().len(), 1);
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_cached_input_needs_no_session_or_network() {
+        let input_dir = temp_dir("fetch_cached");
+        let path = InputLocator::new(2025, 6).with_input_dir(Some(input_dir.clone())).store_path();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "saved\n").unwrap();
+
+        assert_eq!(fetch_input(&Site::new(Offline, "http://unused"), 2025, 6, &path), Ok(Fetched::Cached(path)));
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_failed_download_saves_nothing() {
+        let server = MockServer::start(vec![(
+            "GET",
+            "/2025/day/12/input",
+            404,
+            include_str!("fixtures/input_not_unlocked.txt"),
+        )]);
+        let site = Site::new(Ureq::new(), server.url()).with_session(Some("abc123".to_string()));
+        let input_dir = temp_dir("fetch_locked");
+        let path = InputLocator::new(2025, 12).with_input_dir(Some(input_dir.clone())).store_path();
+
+        assert_eq!(
+            fetch_input(&site, 2025, 12, &path),
+            Err("Failed to download input for 2025 day 12: 2025 day 12 has not unlocked yet".to_string())
+        );
+        assert!(!path.exists());
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+}
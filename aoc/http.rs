@@ -0,0 +1,365 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`BASE_URL`], e.g. to point at a local stand-in.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the Advent of Code servers, as their automation guidelines ask.
+const USER_AGENT: &str = "github.com/antunderwood/advent_of_code aoc runner";
+
+/// Status and body of an HTTP response, whatever the status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP requests the site client needs, so tests can swap the network for a stand-in.
+///
+/// Error statuses are returned as responses; `Err` is only for failing to get a response at all.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// [`Http`] over the network.
+pub struct Ureq {
+    agent: ureq::Agent,
+}
+
+impl Ureq {
+    pub fn new() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(err)) => return Err(err.to_string()),
+        };
+        let status = response.status();
+        let body = response.into_string().map_err(|err| err.to_string())?;
+        Ok(Response { status, body })
+    }
+}
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        let request = self.agent.get(url).set("Cookie", &format!("session={session}"));
+        Self::into_response(request.call())
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let request = self.agent.post(url).set("Cookie", &format!("session={session}"));
+        Self::into_response(request.send_form(form))
+    }
+}
+
+/// Why a request to the site failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SiteError {
+    /// No session token was configured.
+    NoSession,
+    /// The site rejected the session token.
+    BadSession,
+    /// The puzzle has not unlocked yet.
+    NotUnlocked { year: u16, day: u8 },
+    /// Any other unexpected status.
+    Status { status: u16, body: String },
+    /// A page whose message was not understood.
+    Unrecognised(String),
+    Transport(String),
+}
+
+impl fmt::Display for SiteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SiteError::NoSession => write!(
+                f,
+                "no session token, set {SESSION_VAR} or save it in {}",
+                session_file().map_or("~/.config/aoc/session".to_string(), |path| path.display().to_string())
+            ),
+            SiteError::BadSession => write!(f, "the session token was rejected, it may have expired"),
+            SiteError::NotUnlocked { year, day } => write!(f, "{year} day {day} has not unlocked yet"),
+            SiteError::Status { status, body } => write!(f, "unexpected HTTP {status}: {}", body.trim()),
+            SiteError::Unrecognised(text) => write!(f, "unrecognised response: {text}"),
+            SiteError::Transport(err) => write!(f, "request failed: {err}"),
+        }
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Incorrect,
+    /// An answer was submitted too recently; nothing was checked.
+    Wait { seconds: u64 },
+    /// The part has already been solved.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Reads the outcome from the message in the `<article>` of the answer page.
+    pub fn parse(html: &str) -> Result<Self, SiteError> {
+        let text = article_text(html);
+        let outcome = if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if text.contains("You gave an answer too recently") {
+            let seconds = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait))
+                .ok_or_else(|| SiteError::Unrecognised(text.clone()))?;
+            Outcome::Wait { seconds }
+        } else if text.contains("Did you already complete it") {
+            Outcome::AlreadySolved
+        } else {
+            return Err(SiteError::Unrecognised(text));
+        };
+        Ok(outcome)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait { seconds } => write!(f, "submitted too recently, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Text of the page's `<article>` (or the whole page if it has none) with tags removed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses a wait such as "34s", "1m 5s" or "2m".
+fn parse_wait(wait: &str) -> Option<u64> {
+    wait.split_whitespace().try_fold(0, |total, amount| {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        let scale = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 3600,
+            _ => return None,
+        };
+        Some(total + number * scale)
+    })
+}
+
+/// Client for adventofcode.com, or a stand-in for it at another base URL.
+pub struct Site<H> {
+    http: H,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl<H: Http> Site<H> {
+    /// A client for the site at `base_url`, see [`base_url`].
+    pub fn new(http: H, base_url: impl Into<String>) -> Self {
+        Self {
+            http,
+            base_url: base_url.into(),
+            session: None,
+        }
+    }
+
+    pub fn with_session(mut self, session: Option<String>) -> Self {
+        self.session = session;
+        self
+    }
+
+    fn session(&self) -> Result<&str, SiteError> {
+        self.session.as_deref().ok_or(SiteError::NoSession)
+    }
+
+    fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{year}/day/{day}", self.base_url.trim_end_matches('/'))
+    }
+
+    /// Downloads the personal puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, SiteError> {
+        let url = format!("{}/input", self.day_url(year, day));
+        let response = self.http.get(&url, self.session()?).map_err(SiteError::Transport)?;
+        match response.status {
+            200 => Ok(response.body),
+            // The site answers 400 to a missing or unknown session, and 404 until the puzzle unlocks.
+            400 | 401 | 403 => Err(SiteError::BadSession),
+            404 => Err(SiteError::NotUnlocked { year, day }),
+            status => Err(SiteError::Status {
+                status,
+                body: response.body,
+            }),
+        }
+    }
+
+    /// Submits an answer to one part of a day.
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, SiteError> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let response = self
+            .http
+            .post_form(&url, self.session()?, &form)
+            .map_err(SiteError::Transport)?;
+        match response.status {
+            200 => Outcome::parse(&response.body),
+            400 | 401 | 403 => Err(SiteError::BadSession),
+            404 => Err(SiteError::NotUnlocked { year, day }),
+            status => Err(SiteError::Status {
+                status,
+                body: response.body,
+            }),
+        }
+    }
+}
+
+/// [`BASE_URL`], or `AOC_BASE_URL` if it is set.
+pub fn base_url() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string())
+}
+
+/// File the session token is read from when `AOC_SESSION` is not set:
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config.join("aoc").join("session"))
+}
+
+/// The session token from `AOC_SESSION`, or failing that from [`session_file`].
+pub fn session_token() -> Option<String> {
+    let token = env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(session_file()?).ok())?;
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    const NOT_UNLOCKED: &str = include_str!("fixtures/input_not_unlocked.txt");
+    const BAD_SESSION: &str = include_str!("fixtures/input_bad_session.txt");
+
+    fn site(server: &MockServer) -> Site<Ureq> {
+        Site::new(Ureq::new(), server.url()).with_session(Some("abc123".to_string()))
+    }
+
+    #[test]
+    fn test_input_sends_session_cookie() {
+        let server = MockServer::start(vec![("GET", "/2025/day/6/input", 200, "1 2 3\n")]);
+        assert_eq!(site(&server).input(2025, 6), Ok("1 2 3\n".to_string()));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn test_input_before_unlock() {
+        let server = MockServer::start(vec![("GET", "/2025/day/12/input", 404, NOT_UNLOCKED)]);
+        assert_eq!(
+            site(&server).input(2025, 12),
+            Err(SiteError::NotUnlocked { year: 2025, day: 12 })
+        );
+    }
+
+    #[test]
+    fn test_input_with_bad_session() {
+        let server = MockServer::start(vec![("GET", "/2025/day/6/input", 400, BAD_SESSION)]);
+        assert_eq!(site(&server).input(2025, 6), Err(SiteError::BadSession));
+    }
+
+    #[test]
+    fn test_parse_outcomes() {
+        let cases = [
+            (include_str!("fixtures/answer_correct.html"), Outcome::Correct),
+            (include_str!("fixtures/answer_too_high.html"), Outcome::TooHigh),
+            (include_str!("fixtures/answer_too_low.html"), Outcome::TooLow),
+            (include_str!("fixtures/answer_incorrect.html"), Outcome::Incorrect),
+            (include_str!("fixtures/answer_wait.html"), Outcome::Wait { seconds: 65 }),
+            (include_str!("fixtures/answer_already_solved.html"), Outcome::AlreadySolved),
+        ];
+        for (html, expected) in cases {
+            assert_eq!(Outcome::parse(html), Ok(expected));
+        }
+        assert_eq!(
+            Outcome::parse("<article><p>Something new</p></article>"),
+            Err(SiteError::Unrecognised("Something new".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("34s"), Some(34));
+        assert_eq!(parse_wait("1m 5s"), Some(65));
+        assert_eq!(parse_wait("soon"), None);
+    }
+
+    #[test]
+    fn test_submit_posts_level_and_answer() {
+        let server = MockServer::start(vec![(
+            "POST",
+            "/2025/day/5/answer",
+            200,
+            include_str!("fixtures/answer_too_low.html"),
+        )]);
+        assert_eq!(site(&server).submit(2025, 5, 2, "1234"), Ok(Outcome::TooLow));
+        let requests = server.requests();
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+    }
+
+    #[test]
+    fn test_input_without_session_sends_nothing() {
+        let server = MockServer::start(vec![]);
+        let site = site(&server).with_session(None);
+        assert_eq!(site.input(2025, 6), Err(SiteError::NoSession));
+        assert!(server.requests().is_empty());
+    }
+}
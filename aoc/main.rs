@@ -0,0 +1,358 @@
+mod answers;
+mod bench;
+mod fetch;
+mod http;
+#[cfg(test)]
+mod mock_server;
+mod registry;
+mod scaffold;
+mod submit;
+
+use answers::{Answers, PartKey, Verdict};
+use aoc_common::{report, InputLocator};
+use bench::Report;
+use http::{Outcome, Site, Ureq};
+use clap::{Args, Parser, Subcommand};
+use registry::Day;
+use submit::Bounds;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Runs the Advent of Code solutions in this repository.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (or every day) and print the answers.
+    Run(RunArgs),
+    /// Re-run every solution on its real input and compare with the accepted answers.
+    Verify(VerifyArgs),
+    /// Time the parse, part 1 and part 2 stages of each day on its real input.
+    Bench(BenchArgs),
+    /// Download a day's puzzle input to where the runner looks for it.
+    Fetch(FetchArgs),
+    /// Submit an answer, refusing ones that earlier too high or too low answers rule out.
+    Submit(SubmitArgs),
+    /// Create a new day from the template and register it everywhere it needs to be.
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Puzzle year.
+    #[arg(long, default_value_t = 2025)]
+    year: u16,
+    /// Puzzle day; required unless --all is given.
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only run this part (1 or 2).
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file, or from stdin if "-", instead of the day's default input.
+    #[arg(long)]
+    input: Option<String>,
+    /// Run every registered day in sequence.
+    #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+    all: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Accept the current answer of every part that has no accepted answer yet.
+    #[arg(long)]
+    record: bool,
+    /// Answers file to use instead of answers.toml at the workspace root.
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Puzzle year; defaults to every year.
+    #[arg(long)]
+    year: Option<u16>,
+    /// Only benchmark this day.
+    #[arg(long)]
+    day: Option<u8>,
+    /// Timed runs per stage.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// Untimed runs per stage before timing starts.
+    #[arg(long, default_value_t = 1)]
+    warmup: u32,
+    /// Write the timings as JSON to this file.
+    #[arg(long)]
+    json: Option<PathBuf>,
+    /// Compare median times with a JSON report from an earlier run.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Percentage slowdown over the baseline that counts as a regression.
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Puzzle year.
+    #[arg(long, default_value_t = 2025)]
+    year: u16,
+    /// Puzzle day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Puzzle year.
+    #[arg(long, default_value_t = 2025)]
+    year: u16,
+    /// Puzzle day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+    /// Part the answer is for (1 or 2).
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// The answer to submit.
+    #[arg(required_unless_present = "from_run")]
+    answer: Option<String>,
+    /// Submit the answer the registered solution gives on its real input.
+    #[arg(long, conflicts_with = "answer")]
+    from_run: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Puzzle year.
+    #[arg(long, default_value_t = 2025)]
+    year: u16,
+    /// Puzzle day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+fn run_day(day: &Day, part: Option<u8>, input: Option<&str>) -> Result<(), String> {
+    let (year, number) = (day.year(), day.day());
+    let parsed = day.load(input)?;
+
+    for part in part.map_or(1..=2, |part| part..=part) {
+        let answer = day.solve(part, parsed.as_ref())?;
+        println!("{year} day {number} part {part}: {answer}");
+    }
+    Ok(())
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    if args.all {
+        for day in registry::registry() {
+            if !day.has_input() {
+                println!("{} day {}: skipped, no input found", day.year(), day.day());
+                continue;
+            }
+            run_day(&day, None, None)?;
+        }
+        return Ok(());
+    }
+    let day_number = args.day.expect("clap requires --day without --all");
+    let day = registry::find(args.year, day_number)
+        .ok_or_else(|| format!("No solution registered for {} day {day_number}", args.year))?;
+    run_day(&day, args.part, args.input.as_deref())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let path = args.answers.clone().unwrap_or_else(Answers::default_path);
+    let mut answers = Answers::load(&path)?;
+    let mut changed = 0;
+    let mut recorded = 0;
+    for day in registry::registry() {
+        if !day.has_input() {
+            println!("{} day {}: skipped, no input found", day.year(), day.day());
+            continue;
+        }
+        for check in answers::check_day(&day, &answers)? {
+            println!("{check}");
+            match check.verdict {
+                Verdict::Changed { .. } => changed += 1,
+                Verdict::Unrecorded if args.record => {
+                    answers.insert(check.key, check.answer);
+                    recorded += 1;
+                }
+                Verdict::Matches | Verdict::Unrecorded => {}
+            }
+        }
+    }
+    if recorded > 0 {
+        answers
+            .save(&path)
+            .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+        println!("Recorded {recorded} new answer(s) in {}", path.display());
+    }
+    if changed > 0 {
+        return Err(format!("{changed} answer(s) changed"));
+    }
+    Ok(())
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let raw = fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+            Some(Report::from_json(&raw).map_err(|err| format!("{}: {err}", path.display()))?)
+        }
+        None => None,
+    };
+
+    let days: Vec<Day> = registry::registry()
+        .into_iter()
+        .filter(|day| args.year.is_none_or(|year| day.year() == year))
+        .filter(|day| args.day.is_none_or(|number| day.day() == number))
+        .collect();
+    if days.is_empty() {
+        return Err("No registered solutions match the given --year and --day".to_string());
+    }
+
+    let mut report = Report {
+        iterations: args.iterations as usize,
+        warmup: args.warmup as usize,
+        days: Vec::new(),
+    };
+    println!(
+        "{:<16} {:>10} {:>10} {:>10} {:>12}",
+        "stage", "min", "median", "mean", "throughput"
+    );
+    for day in &days {
+        if !day.has_input() {
+            println!("{} day {}: skipped, no input found", day.year(), day.day());
+            continue;
+        }
+        let timings = bench::bench_day(day, report.iterations, report.warmup)?;
+        for (stage, stats) in timings.stages() {
+            println!(
+                "{:<16} {:>10} {:>10} {:>10} {:>12}",
+                format!("{} day {} {stage}", timings.year, timings.day),
+                bench::format_ns(stats.min_ns),
+                bench::format_ns(stats.median_ns),
+                bench::format_ns(stats.mean_ns),
+                timings.throughput(stage).unwrap_or_default()
+            );
+        }
+        report.days.push(timings);
+    }
+
+    if let Some(path) = &args.json {
+        fs::write(path, report.to_json())
+            .map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+        println!("Wrote timings to {}", path.display());
+    }
+
+    if let Some(baseline) = &baseline {
+        let comparisons = bench::compare(baseline, &report, args.threshold);
+        for comparison in &comparisons {
+            println!("{comparison}");
+        }
+        let regressed = comparisons
+            .iter()
+            .filter(|comparison| comparison.regressed)
+            .count();
+        if regressed > 0 {
+            return Err(format!(
+                "{regressed} stage(s) regressed by more than {}%",
+                args.threshold
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), String> {
+    let path = InputLocator::new(args.year, args.day).store_path();
+    let site = Site::new(Ureq::new(), http::base_url()).with_session(http::session_token());
+    let fetched = fetch::fetch_input(&site, args.year, args.day, &path)?;
+    println!("{} day {} input {fetched}", args.year, args.day);
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let key = PartKey {
+        year: args.year,
+        day: args.day,
+        part: args.part,
+    };
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let day = registry::find(args.year, args.day)
+                .ok_or_else(|| format!("No solution registered for {} day {}", args.year, args.day))?;
+            let answer = day.solve(args.part, day.load(None)?.as_ref())?;
+            if aoc_common::Unsolved::is(&answer) {
+                return Err(format!("{key} is not solved yet"));
+            }
+            answer
+        }
+    };
+
+    let bounds_path = Bounds::default_path();
+    let mut bounds = Bounds::load(&bounds_path)?;
+    let site = Site::new(Ureq::new(), http::base_url()).with_session(http::session_token());
+    let outcome = submit::submit(&site, &mut bounds, key, &answer)?;
+    if matches!(outcome, Outcome::TooHigh | Outcome::TooLow) {
+        bounds
+            .save(&bounds_path)
+            .map_err(|err| format!("Failed to write {}: {err}", bounds_path.display()))?;
+    }
+    println!("{key}: {answer} is {outcome}");
+
+    match outcome {
+        Outcome::Correct => {
+            let answers_path = Answers::default_path();
+            let mut answers = Answers::load(&answers_path)?;
+            answers.insert(key, answer);
+            answers
+                .save(&answers_path)
+                .map_err(|err| format!("Failed to write {}: {err}", answers_path.display()))?;
+            println!("Recorded the accepted answer in {}", answers_path.display());
+            Ok(())
+        }
+        Outcome::AlreadySolved => Ok(()),
+        Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect | Outcome::Wait { .. } => {
+            Err(format!("{key} was not accepted"))
+        }
+    }
+}
+
+fn new_day(args: &NewArgs) -> Result<(), String> {
+    let root = aoc_common::locate::workspace_root();
+    for path in scaffold::scaffold(&root, args.year, args.day)? {
+        println!("Wrote {}", path.display());
+    }
+    let fetch_args = FetchArgs {
+        year: args.year,
+        day: args.day,
+    };
+    if http::session_token().is_none() {
+        println!("Fetch the input with: aoc fetch --year {} --day {}", args.year, args.day);
+    } else if let Err(err) = fetch(&fetch_args) {
+        // The day is set up either way, and its input can be fetched later.
+        println!("{err}");
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => new_day(args),
+    };
+    report(result)
+}
@@ -0,0 +1,156 @@
+use aoc_common::solution::{erase, AnySolution};
+use aoc_common::{InputError, InputLocator, Solution};
+use std::any::Any;
+use std::io::BufRead;
+
+/// Solves a day in a single pass over its input as it is read, without holding it all in memory.
+pub type Stream = fn(&mut dyn BufRead) -> Result<String, String>;
+
+/// A registered solution, with a way to stream its input for days that have one.
+pub struct Day {
+    pub solution: Box<dyn AnySolution>,
+    pub stream: Option<Stream>,
+}
+
+impl Day {
+    fn new<S: Solution + 'static>() -> Self {
+        Self {
+            solution: erase::<S>(),
+            stream: None,
+        }
+    }
+
+    fn with_stream(mut self, stream: Stream) -> Self {
+        self.stream = Some(stream);
+        self
+    }
+
+    pub fn year(&self) -> u16 {
+        self.solution.year()
+    }
+
+    pub fn day(&self) -> u8 {
+        self.solution.day()
+    }
+
+    /// Whether the day's puzzle input can be found at its default location; a newly created day has none
+    /// until it is fetched.
+    pub fn has_input(&self) -> bool {
+        InputLocator::new(self.year(), self.day()).locate().is_ok()
+    }
+
+    /// Reads the day's puzzle input from `explicit` if given, otherwise from its default location.
+    ///
+    /// # Returns
+    ///
+    /// The name of where the input was read from, and the input itself.
+    pub fn read_input(&self, explicit: Option<&str>) -> Result<(String, String), InputError> {
+        let source = InputLocator::new(self.year(), self.day())
+            .with_input(explicit)
+            .locate()?;
+        Ok((source.to_string(), source.read()?))
+    }
+
+    /// Reads and parses the day's puzzle input, see [`Day::read_input`].
+    pub fn load(&self, explicit: Option<&str>) -> Result<Box<dyn Any>, String> {
+        let (year, number) = (self.year(), self.day());
+        let (source, raw) = self
+            .read_input(explicit)
+            .map_err(|err| format!("Failed to read input for {year} day {number}: {err}"))?;
+        self.parse(&source, &raw)
+    }
+
+    /// Parses puzzle input read from `source`, naming the source in any error.
+    pub fn parse(&self, source: &str, raw: &str) -> Result<Box<dyn Any>, String> {
+        let (year, number) = (self.year(), self.day());
+        self.solution
+            .parse(raw)
+            .map_err(|err| format!("Failed to parse input for {year} day {number}: {}", err.with_file(source)))
+    }
+
+    /// Runs `part` (1 or 2) on input from [`Day::load`] or [`Day::parse`], naming the part in any error.
+    pub fn solve(&self, part: u8, parsed: &dyn Any) -> Result<String, String> {
+        let (year, number) = (self.year(), self.day());
+        self.solution
+            .solve(part, parsed)
+            .map_err(|err| format!("Failed to solve {year} day {number} part {part}: {err}"))
+    }
+}
+
+/// Counts both parts of 2025 day 1 while reading the rotations.
+fn stream_2025_day1(reader: &mut dyn BufRead) -> Result<String, String> {
+    use advent_of_code_2025_day1::{count_streamed, CountMode, START_POSITION};
+
+    let counts = count_streamed(reader, START_POSITION).map_err(|err| err.to_string())?;
+    let [ends, passes] = CountMode::ALL.map(|mode| counts.hits(mode));
+    Ok(format!("{ends} {passes}"))
+}
+
+/// Every solution the runner knows about, ordered by year then day.
+pub fn registry() -> Vec<Day> {
+    use advent_of_code_2025_day1 as day1;
+    use advent_of_code_2025_day2 as day2;
+    use advent_of_code_2025_day3 as day3;
+    use advent_of_code_2025_day4 as day4;
+    use advent_of_code_2025_day5 as day5;
+
+    let mut days = vec![
+        Day::new::<day1::Day1>().with_stream(stream_2025_day1),
+        Day::new::<day2::Day2>(),
+        Day::new::<day3::Day3>(),
+        Day::new::<day4::Day4>(),
+        Day::new::<day5::Day5>(),
+    ];
+    days.sort_by_key(|day| (day.year(), day.day()));
+    days
+}
+
+/// Looks up a registered day.
+pub fn find(year: u16, day: u8) -> Option<Day> {
+    registry()
+        .into_iter()
+        .find(|entry| entry.year() == year && entry.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered_and_unique() {
+        let keys: Vec<(u16, u8)> = registry().iter().map(|d| (d.year(), d.day())).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_every_found_input_is_readable() {
+        for day in registry().into_iter().filter(Day::has_input) {
+            assert!(
+                day.read_input(None).is_ok(),
+                "Input for {} day {} should be readable",
+                day.year(),
+                day.day()
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_matches_solution() {
+        let day = find(2025, 1).unwrap();
+        let raw = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\nR1000\nL250\n";
+        let parsed = day.parse("example", raw).unwrap();
+        let [part1, part2] = [1, 2].map(|part| day.solve(part, parsed.as_ref()).unwrap());
+        let stream = day.stream.expect("2025 day 1 can be streamed");
+        assert_eq!(stream(&mut raw.as_bytes()), Ok(format!("{part1} {part2}")));
+        assert!(stream(&mut "L5\nX1\n".as_bytes()).unwrap_err().starts_with("2:1:"));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2025, 4).map(|d| d.day()), Some(4));
+        assert!(find(2025, 26).is_none());
+    }
+}
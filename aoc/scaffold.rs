@@ -0,0 +1,222 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("templates/day/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("templates/day/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("templates/day/main.rs.tmpl");
+
+const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+const RUNNER_MANIFEST: &str = "aoc/Cargo.toml";
+const REGISTRY: &str = "aoc/registry.rs";
+const CI_WORKFLOW: &str = ".github/workflows/day-tests.yml";
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `line` before the first line at or after `from` for which `is_end` holds.
+fn insert_before(text: &str, from: usize, is_end: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let end = (from..lines.len()).find(|&index| is_end(lines[index]))?;
+    let mut out: Vec<&str> = lines[..end].to_vec();
+    out.push(line);
+    out.extend(&lines[end..]);
+    Some(out.join("\n") + "\n")
+}
+
+/// Inserts `line` after the line at `index`.
+fn insert_after(text: &str, index: usize, line: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    lines.insert(index + 1, line);
+    lines.join("\n") + "\n"
+}
+
+fn line_index(text: &str, predicate: impl Fn(&str) -> bool) -> Option<usize> {
+    text.lines().position(predicate)
+}
+
+fn last_line_index(text: &str, predicate: impl Fn(&str) -> bool) -> Option<usize> {
+    text.lines().collect::<Vec<_>>().into_iter().rposition(predicate)
+}
+
+fn add_workspace_member(manifest: &str, member: &str) -> Option<String> {
+    let members = line_index(manifest, |line| line.trim_start().starts_with("members = ["))?;
+    insert_before(manifest, members, |line| line.trim() == "]", &format!("    \"{member}\","))
+}
+
+fn add_runner_dependency(manifest: &str, year: u16, day: u8) -> Option<String> {
+    let last = last_line_index(manifest, |line| line.starts_with("advent_of_code_"))?;
+    let dependency = format!("advent_of_code_{year}_day{day} = {{ path = \"../{year}/day{day}\" }}");
+    Some(insert_after(manifest, last, &dependency))
+}
+
+fn add_registry_entry(registry: &str, year: u16, day: u8) -> Option<String> {
+    // Days share an alias across years only if the plain one is taken.
+    let alias = if registry.contains(&format!(" as day{day};")) {
+        format!("day{day}_{year}")
+    } else {
+        format!("day{day}")
+    };
+    let last_use = last_line_index(registry, |line| line.trim_start().starts_with("use advent_of_code_"))?;
+    let registry = insert_after(
+        registry,
+        last_use,
+        &format!("    use advent_of_code_{year}_day{day} as {alias};"),
+    );
+    let days = line_index(&registry, |line| line.contains("let mut days = vec!["))?;
+    insert_before(
+        &registry,
+        days,
+        |line| line.trim() == "];",
+        &format!("        Day::new::<{alias}::Day{day}>(),"),
+    )
+}
+
+fn add_ci_day(workflow: &str, member: &str) -> Option<String> {
+    let matrix = line_index(workflow, |line| {
+        line.trim_start().starts_with("day: [") && line.trim_end().ends_with(']')
+    })?;
+    let lines: Vec<String> = workflow
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == matrix {
+                format!("{}, {member}]", line.trim_end().trim_end_matches(']'))
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates a day crate from the template and registers it with the workspace, the runner and CI.
+///
+/// Nothing is written if the day directory already exists or any file to register it in is not
+/// laid out as expected.
+///
+/// # Returns
+///
+/// Every file created or changed.
+pub fn scaffold(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let member = format!("{year}/day{day}");
+    let dir = root.join(&member);
+    if dir.exists() {
+        return Err(format!("{} already exists, not overwriting it", dir.display()));
+    }
+
+    let read = |relative: &str| {
+        let path = root.join(relative);
+        fs::read_to_string(&path)
+            .map(|text| (path.clone(), text))
+            .map_err(|err| format!("Failed to read {}: {err}", path.display()))
+    };
+    let unexpected = |path: &Path| format!("Could not find where to register {member} in {}", path.display());
+
+    let mut edits = Vec::new();
+    let (path, manifest) = read(WORKSPACE_MANIFEST)?;
+    if manifest.contains(&format!("\"{member}\"")) {
+        return Err(format!("{member} is already a workspace member"));
+    }
+    edits.push((add_workspace_member(&manifest, &member).ok_or_else(|| unexpected(&path))?, path));
+    let (path, manifest) = read(RUNNER_MANIFEST)?;
+    edits.push((add_runner_dependency(&manifest, year, day).ok_or_else(|| unexpected(&path))?, path));
+    let (path, registry) = read(REGISTRY)?;
+    edits.push((add_registry_entry(&registry, year, day).ok_or_else(|| unexpected(&path))?, path));
+    let (path, workflow) = read(CI_WORKFLOW)?;
+    edits.push((add_ci_day(&workflow, &member).ok_or_else(|| unexpected(&path))?, path));
+
+    let created = [
+        ("Cargo.toml".to_string(), CARGO_TEMPLATE),
+        ("lib.rs".to_string(), LIB_TEMPLATE),
+        (format!("day{day}_parts1_and_2.rs"), MAIN_TEMPLATE),
+    ];
+    fs::create_dir_all(&dir).map_err(|err| format!("Failed to create {}: {err}", dir.display()))?;
+    let mut written = Vec::new();
+    let files = created
+        .into_iter()
+        .map(|(name, template)| (render(template, year, day), dir.join(name)))
+        .chain(edits);
+    for (contents, path) in files {
+        fs::write(&path, contents).map_err(|err| format!("Failed to write {}: {err}", path.display()))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::locate::workspace_root;
+    use aoc_common::testing::temp_dir;
+
+    /// A copy of the files `scaffold` edits, in a fresh directory.
+    fn temp_workspace(name: &str) -> PathBuf {
+        let dir = temp_dir(&format!("scaffold_{name}"));
+        for relative in [WORKSPACE_MANIFEST, RUNNER_MANIFEST, REGISTRY, CI_WORKFLOW] {
+            let path = dir.join(relative);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(workspace_root().join(relative), path).unwrap();
+        }
+        dir
+    }
+
+    /// The lines of the workspace members list.
+    fn members(manifest: &str) -> Vec<&str> {
+        let (_, members) = manifest.split_once("members = [\n").unwrap();
+        members.split_once(']').unwrap().0.lines().collect()
+    }
+
+    #[test]
+    fn test_scaffold_creates_and_registers_day() {
+        let root = temp_workspace("new");
+        let read = |relative: &str| fs::read_to_string(root.join(relative)).unwrap();
+        let original_manifest = read(WORKSPACE_MANIFEST);
+        let alias = if read(REGISTRY).contains(" as day12;") { "day12_2099" } else { "day12" };
+        // A year far enough ahead that the real workspace never has it, whatever days get added.
+        let written = scaffold(&root, 2099, 12).unwrap();
+        assert_eq!(written.len(), 7);
+
+        assert!(read("2099/day12/lib.rs").contains("impl Solution for Day12 {\n    const YEAR: u16 = 2099;"));
+        assert!(read("2099/day12/Cargo.toml").contains("name = \"advent_of_code_2099_day12\""));
+        let mut expected = members(&original_manifest);
+        expected.push("    \"2099/day12\",");
+        assert_eq!(members(&read(WORKSPACE_MANIFEST)), expected);
+        assert!(read(RUNNER_MANIFEST).contains("advent_of_code_2099_day12 = { path = \"../2099/day12\" }\n"));
+        let registry = read(REGISTRY);
+        assert!(registry.contains(&format!("    use advent_of_code_2099_day12 as {alias};\n")));
+        assert!(registry.contains(&format!("        Day::new::<{alias}::Day12>(),\n    ];")));
+        assert!(read(CI_WORKFLOW).contains(", 2099/day12]"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_names_binaries_by_year() {
+        let root = temp_workspace("years");
+        scaffold(&root, 2098, 12).unwrap();
+        scaffold(&root, 2099, 12).unwrap();
+        let bin_name = |member: &str| {
+            let manifest = fs::read_to_string(root.join(member).join("Cargo.toml")).unwrap();
+            let bin = manifest.split("[[bin]]").nth(1).unwrap().to_string();
+            bin.lines().find_map(|line| line.strip_prefix("name = ")).unwrap().to_string()
+        };
+        assert_eq!(bin_name("2098/day12"), "\"y2098_day12_parts1_and_2\"");
+        assert_eq!(bin_name("2099/day12"), "\"y2099_day12_parts1_and_2\"");
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_refuses_existing_day() {
+        let root = temp_workspace("existing");
+        scaffold(&root, 2099, 1).unwrap();
+        let registry = fs::read_to_string(root.join(REGISTRY)).unwrap();
+        assert!(registry.contains("use advent_of_code_2099_day1 as day1_2099;"));
+
+        let err = scaffold(&root, 2099, 1).unwrap_err();
+        assert!(err.ends_with("already exists, not overwriting it"), "{err}");
+        assert_eq!(fs::read_to_string(root.join(REGISTRY)).unwrap(), registry);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
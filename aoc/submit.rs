@@ -0,0 +1,221 @@
+use crate::answers::{parse_parts, render_parts, PartKey};
+use crate::http::{Http, Outcome, Site};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the file at the workspace root recording how wrong answers were wrong.
+pub const BOUNDS_FILE: &str = "bounds.toml";
+
+/// What wrong answers have shown about a part's answer: it is above `low` and below `high`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bound {
+    /// Highest answer that was too low.
+    pub low: Option<i64>,
+    /// Lowest answer that was too high.
+    pub high: Option<i64>,
+}
+
+/// Bounds of every part with too high or too low answers, stored as TOML keyed like the answers file:
+///
+/// ```toml
+/// [2025.day5]
+/// part2 = { low = 1000, high = 5000 }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bounds {
+    bounds: BTreeMap<PartKey, Bound>,
+}
+
+impl Bounds {
+    /// Default location of the bounds file.
+    pub fn default_path() -> PathBuf {
+        aoc_common::locate::workspace_root().join(BOUNDS_FILE)
+    }
+
+    /// Loads bounds from `path`, treating a missing file as having no bounds.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(raw) => Self::parse(&raw).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Failed to read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut bounds = Self::default();
+        for (key, value) in parse_parts(raw)? {
+            let limit = |name: &str| match value.get(name) {
+                None => Ok(None),
+                Some(toml::Value::Integer(limit)) => Ok(Some(*limit)),
+                Some(_) => Err(format!("{name} bound for {key} should be an integer")),
+            };
+            let bound = Bound {
+                low: limit("low")?,
+                high: limit("high")?,
+            };
+            bounds.bounds.insert(key, bound);
+        }
+        Ok(bounds)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Renders the bounds as TOML, with years, days and parts in numeric order.
+    pub fn to_toml(&self) -> String {
+        render_parts(self.bounds.iter().map(|(key, bound)| {
+            let mut table = toml::Table::new();
+            if let Some(low) = bound.low {
+                table.insert("low".to_string(), toml::Value::Integer(low));
+            }
+            if let Some(high) = bound.high {
+                table.insert("high".to_string(), toml::Value::Integer(high));
+            }
+            (*key, toml::Value::Table(table))
+        }))
+    }
+
+    pub fn get(&self, key: PartKey) -> Bound {
+        self.bounds.get(&key).copied().unwrap_or_default()
+    }
+
+    /// Rejects a numeric answer that earlier answers have already ruled out.
+    pub fn check(&self, key: PartKey, answer: &str) -> Result<(), String> {
+        let Ok(guess) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+        let bound = self.get(key);
+        if let Some(high) = bound.high.filter(|&high| guess >= high) {
+            return Err(format!("{guess} is too high for {key}, {high} already was"));
+        }
+        if let Some(low) = bound.low.filter(|&low| guess <= low) {
+            return Err(format!("{guess} is too low for {key}, {low} already was"));
+        }
+        Ok(())
+    }
+
+    /// Narrows the bounds of a part from the outcome of submitting `answer`.
+    ///
+    /// Returns whether the bounds changed.
+    pub fn record(&mut self, key: PartKey, answer: &str, outcome: Outcome) -> bool {
+        let Ok(guess) = answer.parse::<i64>() else {
+            return false;
+        };
+        let bound = self.bounds.entry(key).or_default();
+        let before = *bound;
+        match outcome {
+            Outcome::TooHigh => bound.high = Some(bound.high.map_or(guess, |high| high.min(guess))),
+            Outcome::TooLow => bound.low = Some(bound.low.map_or(guess, |low| low.max(guess))),
+            _ => {}
+        }
+        let changed = *bound != before;
+        if *bound == Bound::default() {
+            self.bounds.remove(&key);
+        }
+        changed
+    }
+}
+
+/// Submits an answer unless the recorded bounds already rule it out, then records any new bound.
+pub fn submit<H: Http>(site: &Site<H>, bounds: &mut Bounds, key: PartKey, answer: &str) -> Result<Outcome, String> {
+    bounds.check(key, answer)?;
+    let outcome = site
+        .submit(key.year, key.day, key.part, answer)
+        .map_err(|err| format!("Failed to submit answer for {key}: {err}"))?;
+    bounds.record(key, answer, outcome);
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::Ureq;
+    use crate::mock_server::MockServer;
+
+    const KEY: PartKey = PartKey {
+        year: 2025,
+        day: 5,
+        part: 2,
+    };
+
+    fn site(server: &MockServer) -> Site<Ureq> {
+        Site::new(Ureq::new(), server.url()).with_session(Some("abc123".to_string()))
+    }
+
+    #[test]
+    fn test_record_narrows_bounds() {
+        let mut bounds = Bounds::default();
+        assert!(bounds.record(KEY, "500", Outcome::TooHigh));
+        assert!(bounds.record(KEY, "100", Outcome::TooLow));
+        assert!(!bounds.record(KEY, "700", Outcome::TooHigh));
+        assert!(!bounds.record(KEY, "abc", Outcome::TooLow));
+        assert!(!bounds.record(KEY, "300", Outcome::Incorrect));
+        assert_eq!(
+            bounds.get(KEY),
+            Bound {
+                low: Some(100),
+                high: Some(500)
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_rejects_answers_outside_bounds() {
+        let mut bounds = Bounds::default();
+        bounds.record(KEY, "500", Outcome::TooHigh);
+        bounds.record(KEY, "100", Outcome::TooLow);
+        assert_eq!(
+            bounds.check(KEY, "500"),
+            Err("500 is too high for 2025 day 5 part 2, 500 already was".to_string())
+        );
+        assert!(bounds.check(KEY, "99").is_err());
+        assert_eq!(bounds.check(KEY, "101"), Ok(()));
+        assert_eq!(bounds.check(KEY, "not a number"), Ok(()));
+    }
+
+    #[test]
+    fn test_bounds_round_trip() {
+        let mut bounds = Bounds::default();
+        bounds.record(KEY, "500", Outcome::TooHigh);
+        bounds.record(PartKey { part: 1, ..KEY }, "7", Outcome::TooLow);
+        let raw = bounds.to_toml();
+        assert_eq!(raw, "[2025.day5]\npart1 = { low = 7 }\npart2 = { high = 500 }\n");
+        assert_eq!(Bounds::parse(&raw), Ok(bounds));
+    }
+
+    #[test]
+    fn test_submit_records_bounds_and_skips_ruled_out_answers() {
+        let server = MockServer::start(vec![(
+            "POST",
+            "/2025/day/5/answer",
+            200,
+            include_str!("fixtures/answer_too_high.html"),
+        )]);
+        let site = site(&server);
+        let mut bounds = Bounds::default();
+
+        assert_eq!(submit(&site, &mut bounds, KEY, "900"), Ok(Outcome::TooHigh));
+        assert_eq!(bounds.get(KEY).high, Some(900));
+        assert!(submit(&site, &mut bounds, KEY, "950").is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_submit_reports_wait() {
+        let server = MockServer::start(vec![(
+            "POST",
+            "/2025/day/5/answer",
+            200,
+            include_str!("fixtures/answer_wait.html"),
+        )]);
+        let mut bounds = Bounds::default();
+        assert_eq!(
+            submit(&site(&server), &mut bounds, KEY, "900"),
+            Ok(Outcome::Wait { seconds: 65 })
+        );
+        assert_eq!(bounds, Bounds::default());
+    }
+}
@@ -0,0 +1,240 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Error raised when puzzle input cannot be parsed.
+///
+/// Parsers fill in what they know about where the problem is; callers further up add the rest,
+/// e.g. a line parser sets the column and [`crate::input::parse_lines_with`] adds the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Name of the input the error was found in, e.g. its path.
+    pub file: Option<String>,
+    /// 1-based line number of the offending text.
+    pub line: Option<usize>,
+    /// 1-based column of the offending text within its line.
+    pub column: Option<usize>,
+    /// The offending text itself.
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: None,
+            text: None,
+            message: message.into(),
+        }
+    }
+
+    /// An error about `token`, a slice of `within`, whose column is worked out from its position.
+    pub fn invalid(within: &str, token: &str, message: impl Into<String>) -> Self {
+        Self::new(message)
+            .at_column(column_of(within, token))
+            .with_text(token)
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Attaches the 1-based line number the error was found on, unless one is already known.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Attaches the 1-based column the error was found at, unless one is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Attaches the offending text, unless it is already known.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text.get_or_insert_with(|| text.into());
+        self
+    }
+
+    /// Moves the error down by `lines` lines, for errors found in a section of a larger input.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line = self.line.map(|line| line + lines);
+        self
+    }
+
+    /// Moves the error right by `columns` columns, for errors found in part of a line.
+    pub fn shift_columns(mut self, columns: usize) -> Self {
+        self.column = self.column.map(|column| column + columns);
+        self
+    }
+}
+
+/// 1-based column at which `token` starts within `within`, or 1 if `token` is not a slice of it.
+pub fn column_of(within: &str, token: &str) -> usize {
+    offset_of(within, token).map_or(1, |offset| within[..offset].chars().count() + 1)
+}
+
+/// Byte offset at which `part` starts within `whole`, if `part` is a slice of it.
+pub(crate) fn offset_of(whole: &str, part: &str) -> Option<usize> {
+    let start = whole.as_ptr() as usize;
+    let offset = (part.as_ptr() as usize).checked_sub(start)?;
+    (offset + part.len() <= whole.len()).then_some(offset)
+}
+
+impl fmt::Display for ParseError {
+    /// Renders as `file:line:column: message: "text"`, leaving out whatever is unknown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut location = Vec::new();
+        if let Some(file) = &self.file {
+            location.push(file.clone());
+        }
+        if let Some(line) = self.line {
+            location.push(line.to_string());
+            if let Some(column) = self.column {
+                location.push(column.to_string());
+            }
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(":"))?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, ": {text:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error raised when puzzle input cannot be found or read.
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the candidate paths exist.
+    NotFound {
+        year: u16,
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    /// The input was found but could not be read.
+    Io { source_name: String, error: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { year, day, tried } => {
+                write!(f, "no input found for {year} day {day}, tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Io { source_name, error } => {
+                write!(f, "failed to read {source_name}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Any error a solution binary can fail with.
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse(ParseError),
+    /// A part has no answer for input that parsed.
+    Solve(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Input(err) => write!(f, "{err}"),
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Solve(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Input(err) => Some(err),
+            Error::Parse(err) => Some(err),
+            Error::Solve(_) => None,
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Self {
+        Error::Input(err)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+/// Turns the result of a binary's work into its exit code, printing any error to stderr.
+pub fn report<E: fmt::Display>(result: Result<(), E>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_with_full_location() {
+        let line = "12-x4";
+        let err = ParseError::invalid(line, &line[3..], "invalid end of range")
+            .at_line(3)
+            .with_file("2025/day5/data.txt");
+        assert_eq!(
+            err.to_string(),
+            "2025/day5/data.txt:3:4: invalid end of range: \"x4\""
+        );
+    }
+
+    #[test]
+    fn test_display_without_location() {
+        let err = ParseError::new("input must contain a blank line");
+        assert_eq!(err.to_string(), "input must contain a blank line");
+    }
+
+    #[test]
+    fn test_innermost_location_wins() {
+        let err = ParseError::new("bad").at_line(2).at_column(5).at_line(9).shift_lines(10);
+        assert_eq!((err.line, err.column), (Some(12), Some(5)));
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = "L68 R";
+        assert_eq!(column_of(line, &line[4..]), 5);
+        assert_eq!(column_of(line, "R"), 1);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::error::ParseError;
+use std::collections::HashMap;
+
+/// Simple grid wrapper that lets us address cells via cartesian coordinates.
+///
+/// `(0, 0)` is the top left cell, `x` increases to the right and `y` increases downwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    width: i32,
+    height: i32,
+    cells: HashMap<(i32, i32), char>,
+}
+
+impl Grid {
+    /// Builds a grid from lines of characters, one line per row.
+    ///
+    /// Every row must be as wide as the first.
+    pub fn parse(raw: &str) -> Result<Self, ParseError> {
+        let mut cells = HashMap::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in raw.lines().enumerate() {
+            let y = y as i32;
+            let line_width = line.chars().count() as i32;
+            if y == 0 {
+                width = line_width;
+            } else if line_width != width {
+                return Err(ParseError::new(format!("expected a row of width {width}, found {line_width}"))
+                    .at_line(y as usize + 1)
+                    .with_text(line));
+            }
+            height = y + 1;
+
+            for (x, value) in line.chars().enumerate() {
+                cells.insert((x as i32, y), value);
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<char> {
+        self.cells.get(&(x, y)).copied()
+    }
+
+    pub fn set(&mut self, x: i32, y: i32, value: char) {
+        self.cells.insert((x, y), value);
+    }
+
+    /// Iterates over every coordinate of the grid, column by column.
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let height = self.height;
+        (0..self.width).flat_map(move |x| (0..height).map(move |y| (x, y)))
+    }
+
+    /// Returns the values of the (up to) eight cells surrounding `(x, y)`.
+    pub fn get_adjacent_values(&self, x: i32, y: i32) -> Vec<char> {
+        let mut values = Vec::new();
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                if let Some(val) = self.get(x + dx, y + dy) {
+                    values.push(val);
+                }
+            }
+        }
+        values
+    }
+
+    /// Counts the cells surrounding `(x, y)` that hold `value`.
+    pub fn count_adjacent(&self, x: i32, y: i32, value: char) -> usize {
+        self.get_adjacent_values(x, y)
+            .into_iter()
+            .filter(|&val| val == value)
+            .count()
+    }
+
+    /// Counts the cells of the whole grid that hold `value`.
+    pub fn count(&self, value: char) -> usize {
+        self.cells.values().filter(|&&val| val == value).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dimensions_and_get() {
+        let grid = Grid::parse("ab.\n.c.\n").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(0, 0), Some('a'));
+        assert_eq!(grid.get(1, 1), Some('c'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_ragged_rows() {
+        let err = Grid::parse("...\n..\n...\n").unwrap_err();
+        assert_eq!(err.to_string(), "2: expected a row of width 3, found 2: \"..\"");
+    }
+
+    #[test]
+    fn test_count_adjacent_at_edges() {
+        let grid = Grid::parse("@@@\n@.@\n@@@\n").unwrap();
+        assert_eq!(grid.count_adjacent(1, 1, '@'), 8);
+        assert_eq!(grid.count_adjacent(0, 0, '@'), 2);
+        assert_eq!(grid.count_adjacent(0, 0, '.'), 1);
+    }
+
+    #[test]
+    fn test_set_and_count() {
+        let mut grid = Grid::parse("@@\n@@\n").unwrap();
+        grid.set(0, 1, '.');
+        assert_eq!(grid.count('@'), 3);
+        assert_eq!(grid.positions().count(), 4);
+    }
+}
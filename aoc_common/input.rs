@@ -0,0 +1,108 @@
+use crate::error::{offset_of, ParseError};
+
+/// Parses line number `number` (counting from 1) of some input with `parse_line`, unless it is blank.
+///
+/// `parse_line` is given the trimmed line; any error it returns has the line number and the
+/// line's leading whitespace added to its position.
+pub fn parse_line_with<T, F>(number: usize, line: &str, parse_line: F) -> Option<Result<T, ParseError>>
+where
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    let indent = line[..offset_of(line, trimmed).unwrap_or(0)].chars().count();
+    Some(parse_line(trimmed).map_err(|err| {
+        err.shift_columns(indent)
+            .at_line(number)
+            .at_column(indent + 1)
+            .with_text(trimmed)
+    }))
+}
+
+/// Parses every non-blank line of some raw input with `parse_line`, as [`parse_line_with`] does.
+///
+/// # Returns
+///
+/// The parsed values in input order, or the error of the first line that failed to parse.
+pub fn parse_lines_with<T, F>(raw: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    raw.lines()
+        .enumerate()
+        .filter_map(|(index, line)| parse_line_with(index + 1, line, &mut parse_line))
+        .collect()
+}
+
+/// Converts a line of decimal digits e.g. "8119" into its digits [8, 1, 1, 9].
+pub fn parse_digits(line: &str) -> Result<Vec<u32>, ParseError> {
+    line.char_indices()
+        .map(|(offset, c)| {
+            c.to_digit(10).ok_or_else(|| {
+                ParseError::invalid(line, &line[offset..offset + c.len_utf8()], "expected a digit")
+            })
+        })
+        .collect()
+}
+
+/// Splits input made of two sections separated by a blank line, with either "\n" or "\r\n" line endings.
+pub fn split_sections(raw: &str) -> Option<(&str, &str)> {
+    let mut offset = 0;
+    for line in raw.split_inclusive('\n') {
+        if line.ends_with('\n') && line.trim().is_empty() {
+            let first = raw[..offset].strip_suffix('\n').unwrap_or(&raw[..offset]);
+            return Some((first.strip_suffix('\r').unwrap_or(first), &raw[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Number of lines of `raw` that come before `section`, a slice of it.
+///
+/// Errors found when parsing `section` on its own can be shifted by this to give lines of `raw`.
+pub fn lines_before(raw: &str, section: &str) -> usize {
+    offset_of(raw, section).map_or(0, |offset| raw[..offset].matches('\n').count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines_with_shifts_columns_by_indent() {
+        let err = parse_lines_with("11\n  12x4", parse_digits).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(5)));
+        assert_eq!(err.text.as_deref(), Some("x"));
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        assert_eq!(parse_digits("8119"), Ok(vec![8, 1, 1, 9]));
+        let err = parse_digits("81a9").unwrap_err();
+        assert_eq!((err.column, err.text.as_deref()), (Some(3), Some("a")));
+    }
+
+    #[test]
+    fn test_lines_before() {
+        let raw = "3-5\n10-14\n\n1\n5\n";
+        let (_, second) = split_sections(raw).unwrap();
+        assert_eq!(lines_before(raw, second), 3);
+    }
+
+    #[test]
+    fn test_split_sections() {
+        assert_eq!(split_sections("3-5\n10-14\n\n1\n5\n"), Some(("3-5\n10-14", "1\n5\n")));
+        assert_eq!(split_sections("3-5\n10-14\n"), None);
+    }
+
+    #[test]
+    fn test_split_sections_with_crlf_line_endings() {
+        let raw = "3-5\r\n10-14\r\n\r\n1\r\n5\r\n";
+        let (first, second) = split_sections(raw).unwrap();
+        assert_eq!((first, second), ("3-5\r\n10-14", "1\r\n5\r\n"));
+        assert_eq!(lines_before(raw, second), 3);
+    }
+}
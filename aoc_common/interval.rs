@@ -0,0 +1,151 @@
+use crate::error::ParseError;
+use std::str::FromStr;
+
+/// An inclusive range of integers written as `start-end` e.g. "3-5".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Parses a `start-end` range e.g. "10-14".
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let trimmed = line.trim();
+        let (start, end) = trimmed
+            .split_once('-')
+            .ok_or_else(|| ParseError::invalid(line, trimmed, "expected a range like start-end"))?;
+
+        let parse_bound = |bound: &str, name: &str| {
+            bound
+                .parse::<i64>()
+                .map_err(|err| ParseError::invalid(line, bound, format!("invalid {name} of range ({err})")))
+        };
+        let start = parse_bound(start, "start")?;
+        let end = parse_bound(end, "end")?;
+
+        Ok(Self { start, end })
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        value >= self.start && value <= self.end
+    }
+
+    /// Number of integers covered by the interval.
+    pub fn len(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Interval::parse(s)
+    }
+}
+
+/// Merges overlapping and adjoining intervals.
+///
+/// # Arguments
+///
+/// * `intervals` - Intervals in any order e.g. [3-5, 10-14, 16-20, 12-18]
+///
+/// # Returns
+///
+/// Disjoint intervals sorted by start e.g. [3-5, 10-20]
+pub fn merge_intervals(intervals: &[Interval]) -> Vec<Interval> {
+    let mut sorted_intervals = intervals.to_vec();
+    sorted_intervals.sort_by_key(|interval| interval.start);
+
+    let mut merged_intervals: Vec<Interval> = Vec::new();
+    for interval in sorted_intervals {
+        match merged_intervals.last_mut() {
+            Some(last) if last.end.saturating_add(1) >= interval.start => {
+                last.end = last.end.max(interval.end);
+            }
+            _ => merged_intervals.push(interval),
+        }
+    }
+    merged_intervals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_intervals(input: &[&str]) -> Vec<Interval> {
+        input.iter().map(|s| Interval::parse(s).unwrap()).collect()
+    }
+
+    fn as_pairs(intervals: &[Interval]) -> Vec<(i64, i64)> {
+        intervals.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn test_parse_contains_and_len() {
+        let interval = Interval::parse(" 10-14 ").unwrap();
+        assert_eq!(interval, Interval::new(10, 14));
+        assert!(interval.contains(10));
+        assert!(interval.contains(14));
+        assert!(!interval.contains(15));
+        assert_eq!(interval.len(), 5);
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_offending_text() {
+        let err = Interval::parse("10-1x").unwrap_err();
+        assert_eq!((err.column, err.text.as_deref()), (Some(4), Some("1x")));
+        let err = Interval::parse("  1014").unwrap_err();
+        assert_eq!((err.column, err.text.as_deref()), (Some(3), Some("1014")));
+        assert!(Interval::parse("-5-10").is_err());
+    }
+
+    #[test]
+    fn merge_simple_overlaps_and_disjoint() {
+        let input = parse_intervals(&["1-5", "3-10", "20-25", "22-30", "40-45"]);
+        let expected = vec![(1, 10), (20, 30), (40, 45)];
+
+        let result = merge_intervals(&input);
+
+        assert_eq!(as_pairs(&result), expected);
+    }
+
+    #[test]
+    fn merge_nested_overlapping_and_duplicates() {
+        let input = parse_intervals(&[
+            "5-15",
+            "1-3",
+            "2-6",
+            "8-12",
+            "30-40",
+            "35-45",
+            "50-60",
+            "50-60",
+        ]);
+        let expected = vec![(1, 15), (30, 45), (50, 60)];
+
+        let result = merge_intervals(&input);
+
+        assert_eq!(as_pairs(&result), expected);
+    }
+
+    #[test]
+    fn merge_adjoining_ranges() {
+        // Adjacency (end + 1 == next_start) counts as merge.
+        let input = parse_intervals(&["1-5", "6-10", "11-20", "25-30", "30-35"]);
+        let expected = vec![(1, 20), (25, 35)];
+
+        let result = merge_intervals(&input);
+
+        assert_eq!(as_pairs(&result), expected);
+    }
+}
@@ -0,0 +1,16 @@
+//! Shared helpers for the Advent of Code solutions: the `Solution` trait, input location and
+//! loading, parsing, grids and integer intervals, and helpers for their tests.
+
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod locate;
+pub mod solution;
+pub mod testing;
+
+pub use error::{report, Error, InputError, ParseError};
+pub use grid::Grid;
+pub use interval::Interval;
+pub use locate::InputLocator;
+pub use solution::{Solution, Unsolved};
@@ -0,0 +1,258 @@
+use crate::error::InputError;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory of puzzle inputs laid out as `<year>/day<day>/data.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Passing this as the input path reads the puzzle input from stdin.
+pub const STDIN: &str = "-";
+
+/// Name of the input file inside a day directory.
+pub const INPUT_FILE: &str = "data.txt";
+
+/// The repository root, found from this crate's manifest so it does not depend on the working directory.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc_common should live inside the workspace")
+        .to_path_buf()
+}
+
+/// Path of a day's input below an inputs directory laid out like the repository.
+pub fn day_input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string())
+        .join(format!("day{day}"))
+        .join(INPUT_FILE)
+}
+
+/// Where a puzzle input was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Stdin => {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw).map(|_| raw)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError::Io {
+            source_name: self.to_string(),
+            error,
+        })
+    }
+
+    /// Opens the input for reading a piece at a time rather than all at once.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(io::BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| InputError::Io {
+                    source_name: self.to_string(),
+                    error,
+                }),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Resolves a day's puzzle input, in order of preference, from:
+///
+/// 1. an explicit `--input` path, or `-` for stdin
+/// 2. the `AOC_INPUT_DIR` directory
+/// 3. the day's directory in this repository
+#[derive(Debug, Clone)]
+pub struct InputLocator {
+    year: u16,
+    day: u8,
+    explicit: Option<String>,
+    input_dir: Option<PathBuf>,
+    root: PathBuf,
+}
+
+impl InputLocator {
+    /// A locator for the given day, honouring `AOC_INPUT_DIR` if it is set.
+    pub fn new(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            day,
+            explicit: None,
+            input_dir: env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            root: workspace_root(),
+        }
+    }
+
+    /// Uses an explicit input path (e.g. from `--input`) instead of searching.
+    pub fn with_input(mut self, input: Option<impl Into<String>>) -> Self {
+        self.explicit = input.map(Into::into);
+        self
+    }
+
+    /// Overrides the inputs directory otherwise taken from `AOC_INPUT_DIR`.
+    pub fn with_input_dir(mut self, input_dir: Option<PathBuf>) -> Self {
+        self.input_dir = input_dir;
+        self
+    }
+
+    /// Overrides the repository root the day directories are found in.
+    pub fn with_root(mut self, root: PathBuf) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Every path that would be tried, in order.
+    pub fn candidates(&self) -> Vec<PathBuf> {
+        if let Some(explicit) = &self.explicit {
+            return vec![PathBuf::from(explicit)];
+        }
+        let mut candidates = Vec::new();
+        if let Some(input_dir) = &self.input_dir {
+            candidates.push(day_input_path(input_dir, self.year, self.day));
+        }
+        candidates.push(day_input_path(&self.root, self.year, self.day));
+        candidates
+    }
+
+    /// Where a downloaded input should be saved so that [`InputLocator::locate`] finds it: the
+    /// `AOC_INPUT_DIR` directory if set, otherwise the day's directory in this repository.
+    pub fn store_path(&self) -> PathBuf {
+        day_input_path(self.input_dir.as_ref().unwrap_or(&self.root), self.year, self.day)
+    }
+
+    /// Finds the first candidate that exists.
+    pub fn locate(&self) -> Result<InputSource, InputError> {
+        if self.explicit.as_deref() == Some(STDIN) {
+            return Ok(InputSource::Stdin);
+        }
+        let candidates = self.candidates();
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(InputSource::File(path.clone())),
+            None => Err(InputError::NotFound {
+                year: self.year,
+                day: self.day,
+                tried: candidates,
+            }),
+        }
+    }
+
+    /// Finds and reads the puzzle input.
+    pub fn read(&self) -> Result<String, InputError> {
+        self.locate()?.read()
+    }
+}
+
+/// Reads a day's puzzle input, honouring an `--input` flag on the command line.
+pub fn read_input(year: u16, day: u8) -> Result<String, InputError> {
+    InputLocator::new(year, day)
+        .with_input(input_flag(env::args().skip(1)))
+        .read()
+}
+
+/// Picks the value of an `--input <path>` or `--input=<path>` flag out of command line arguments.
+pub fn input_flag<I: IntoIterator<Item = String>>(args: I) -> Option<String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--input" {
+            return args.next();
+        }
+        if let Some(value) = arg.strip_prefix("--input=") {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::temp_dir;
+
+    #[test]
+    fn test_looks_in_day_dir_found_from_manifest_dir() {
+        let locator = InputLocator::new(2025, 4).with_input_dir(None);
+        let expected = workspace_root().join("2025").join("day4").join("data.txt");
+        assert_eq!(locator.candidates(), vec![expected]);
+    }
+
+    #[test]
+    fn test_input_dir_takes_precedence_over_root() {
+        let input_dir = temp_dir("locate_precedence");
+        let path = day_input_path(&input_dir, 2025, 4);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "@@\n").unwrap();
+
+        let locator = InputLocator::new(2025, 4).with_input_dir(Some(input_dir.clone()));
+        assert_eq!(locator.read().unwrap(), "@@\n");
+        fs::remove_dir_all(input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_falls_back_to_root_when_input_dir_lacks_day() {
+        let input_dir = temp_dir("locate_fallback");
+        let root = temp_dir("locate_fallback_root");
+        let path = day_input_path(&root, 2025, 3);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "811\n").unwrap();
+
+        let locator = InputLocator::new(2025, 3)
+            .with_input_dir(Some(input_dir.clone()))
+            .with_root(root.clone());
+        assert_eq!(locator.locate().unwrap(), InputSource::File(path));
+        fs::remove_dir_all(input_dir).unwrap();
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_explicit_input_and_stdin() {
+        let locator = InputLocator::new(2025, 4).with_input(Some("some/where.txt"));
+        assert_eq!(locator.candidates(), vec![PathBuf::from("some/where.txt")]);
+        let locator = InputLocator::new(2025, 4).with_input(Some(STDIN));
+        assert_eq!(locator.locate().unwrap(), InputSource::Stdin);
+    }
+
+    #[test]
+    fn test_not_found_names_every_path_tried() {
+        let locator = InputLocator::new(2025, 25)
+            .with_input_dir(Some(PathBuf::from("/nonexistent/inputs")))
+            .with_root(PathBuf::from("/nonexistent/repo"));
+        let message = locator.locate().unwrap_err().to_string();
+        assert_eq!(
+            message,
+            "no input found for 2025 day 25, tried:\n  /nonexistent/inputs/2025/day25/data.txt\n  /nonexistent/repo/2025/day25/data.txt"
+        );
+    }
+
+    #[test]
+    fn test_store_path_prefers_input_dir() {
+        let locator = InputLocator::new(2025, 6).with_root(PathBuf::from("/repo"));
+        let stored = locator.clone().with_input_dir(Some(PathBuf::from("/inputs"))).store_path();
+        assert_eq!(stored, PathBuf::from("/inputs/2025/day6/data.txt"));
+        assert_eq!(locator.with_input_dir(None).store_path(), PathBuf::from("/repo/2025/day6/data.txt"));
+    }
+
+    #[test]
+    fn test_input_flag() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(input_flag(args(&["day4", "--input", "x.txt"])), Some("x.txt".to_string()));
+        assert_eq!(input_flag(args(&["day4", "--input=-"])), Some("-".to_string()));
+        assert_eq!(input_flag(args(&["day4"])), None);
+    }
+}
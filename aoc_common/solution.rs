@@ -0,0 +1,167 @@
+use crate::error::{Error, ParseError};
+use crate::locate::{input_flag, InputLocator};
+use std::any::Any;
+use std::env;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+/// A puzzle solution split into a parsing stage and one stage per part.
+///
+/// Both parts work from the same parsed input, so each stage can be run, timed and tested on its own.
+/// A part fails if the parsed input has no answer, e.g. when it is valid but too small for that part.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// The puzzle input once parsed.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Error>;
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Error>;
+}
+
+/// Locates, reads and parses a solution's puzzle input, honouring an `--input` flag on the command line.
+///
+/// Parse errors are tagged with where the input was read from.
+pub fn load_input<S: Solution>() -> Result<S::Input, Error> {
+    load_input_from::<S>(input_flag(env::args().skip(1)).as_deref())
+}
+
+/// Locates, reads and parses a solution's puzzle input from `input` if given, otherwise from its
+/// default location, for binaries that parse their own command line.
+pub fn load_input_from<S: Solution>(input: Option<&str>) -> Result<S::Input, Error> {
+    let source = InputLocator::new(S::YEAR, S::DAY).with_input(input).locate()?;
+    let raw = source.read()?;
+    S::parse(&raw).map_err(|err| err.with_file(source.to_string()).into())
+}
+
+/// Answer for a part that has not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Unsolved {
+    const TEXT: &'static str = "unsolved";
+
+    /// Whether an answer, once rendered, came from an unsolved part.
+    pub fn is(answer: &str) -> bool {
+        answer == Self::TEXT
+    }
+}
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Self::TEXT)
+    }
+}
+
+/// Object safe view of a [`Solution`], so solutions with different input types can be stored together.
+pub trait AnySolution {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Runs `part` (1 or 2) on input returned by [`AnySolution::parse`].
+    ///
+    /// # Panics
+    ///
+    /// If `part` is not 1 or 2, or `parsed` was not produced by this solution.
+    fn solve(&self, part: u8, parsed: &dyn Any) -> Result<String, Error>;
+}
+
+struct Erased<S>(PhantomData<S>);
+
+impl<S> AnySolution for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, part: u8, parsed: &dyn Any) -> Result<String, Error> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input should come from the same solution");
+        match part {
+            1 => Ok(S::part1(input)?.to_string()),
+            2 => Ok(S::part2(input)?.to_string()),
+            _ => panic!("Invalid part {part}, expected 1 or 2"),
+        }
+    }
+}
+
+/// Boxes a solution behind [`AnySolution`].
+pub fn erase<S>() -> Box<dyn AnySolution>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    Box::new(Erased::<S>(PhantomData))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split(',')
+                .map(|num| num.trim().parse().map_err(|_| ParseError::new("not a number")))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Error> {
+            let sum = input.iter().sum::<i64>();
+            if sum < 0 {
+                return Err(ParseError::new("the sum is negative").into());
+            }
+            Ok(sum)
+        }
+
+        fn part2(_input: &Self::Input) -> Result<impl Display, Error> {
+            Ok(Unsolved)
+        }
+    }
+
+    #[test]
+    fn test_erased_solution_runs_each_stage() {
+        let solution = erase::<Sum>();
+        assert_eq!((solution.year(), solution.day()), (2015, 1));
+        let parsed = solution.parse("1, 2, 3").unwrap();
+        assert_eq!(solution.solve(1, parsed.as_ref()).unwrap(), "6");
+        assert!(Unsolved::is(&solution.solve(2, parsed.as_ref()).unwrap()));
+    }
+
+    #[test]
+    fn test_erased_solution_reports_part_errors() {
+        let solution = erase::<Sum>();
+        let parsed = solution.parse("1, -3").unwrap();
+        let err = solution.solve(1, parsed.as_ref()).unwrap_err();
+        assert_eq!(err.to_string(), "the sum is negative");
+    }
+
+    #[test]
+    fn test_erased_solution_reports_parse_errors() {
+        let solution = erase::<Sum>();
+        assert_eq!(solution.parse("1, x").err(), Some(ParseError::new("not a number")));
+    }
+}
@@ -0,0 +1,11 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// A fresh, empty directory for a test to write into, named after the test and unique to this process.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}